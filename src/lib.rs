@@ -0,0 +1,43 @@
+//! Wordle rules, word lists and solver, shared by the `wordle` terminal game
+//! and by bots or tools built on top of it.
+//!
+//! A [`Config`] holds the word lists and the answer and scores guesses with
+//! [`Config::check`]. A [`Solver`] narrows down the possible answers from the
+//! revelations it is given and ranks the next guesses. An [`Engine`] plays a
+//! whole game from the [`Action`]s of any front end. Word list files are
+//! read with [`wordlist::load`], and bots play against a host through the
+//! line [`protocol`].
+//!
+//! ```
+//! use wordle::{Config, Solver, State};
+//!
+//! let words: Vec<String> = ["crane", "slate", "caper"].map(String::from).into();
+//! let mut config = Config::new(None, words.clone(), words.clone()).unwrap();
+//! config.choose_answer(2);
+//!
+//! let revelation = config.check("crane");
+//! let first = (&revelation).into_iter().next().unwrap();
+//! assert_eq!(first.state, State::Correct);
+//!
+//! let mut solver = Solver::new(words);
+//! solver.add_revelations(&revelation);
+//! assert_eq!(solver.possibilities(), ["caper"]);
+//! ```
+
+#![warn(missing_docs)]
+
+mod modules;
+
+pub use modules::{
+    config::{Config, MAX_WORD_LENGTH, MIN_WORD_LENGTH},
+    daily,
+    engine::{Action, Engine, Outcome, Status},
+    pattern::{Pattern, PatternMatrix},
+    protocol,
+    puzzle::{Puzzle, PuzzleCode},
+    revelation::{LetterRevelation, WordRevelation},
+    settings::Settings,
+    solver::{GuessScore, HintViolation, LetterConstraint, Solver, Strategy},
+    state::State,
+    wordlist::{self, WordListError},
+};
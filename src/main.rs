@@ -1,7 +1,78 @@
-use std::io;
-pub mod modules;
-use modules::game::Game;
+use std::{env, io, process, rc::Rc};
+mod tui;
+use tui::{
+    bench,
+    cli::{self, Command},
+    commands,
+    game::Game,
+    host, paths, text,
+};
+use wordle::{Config, PatternMatrix, Settings};
 fn main() -> io::Result<()> {
-    let mut game = Game::start();
-    ratatui::run(|terminal| game.run(terminal))
+    let command = cli::parse(env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{error}\n{}", cli::USAGE);
+        process::exit(2);
+    });
+    match command {
+        Command::Play(settings) => {
+            let mut game = Game::start(settings);
+            game.offer_saved_game();
+            ratatui::run(|terminal| game.run(terminal))
+        }
+        Command::Text(settings) => {
+            let mut engine = text::engine(settings).unwrap_or_else(|error| {
+                eprintln!("{error}");
+                process::exit(1);
+            });
+            text::play(&mut engine, io::stdin().lock(), io::stdout().lock())
+        }
+        Command::Solve(settings, options) => {
+            let (config, matrix) = load_lists(&settings);
+            commands::solve(config, matrix, &options).unwrap_or_else(|error| {
+                eprintln!("{error}");
+                process::exit(1);
+            });
+            Ok(())
+        }
+        Command::Bench(settings, options) => {
+            let (config, matrix) = load_lists(&settings);
+            let report = bench::run(config, matrix, &options, settings.max_attempts);
+            print!("{report}");
+            Ok(())
+        }
+        Command::Host(settings, options) => {
+            let config = load_config(&settings);
+            let report = host::run(config, settings, &options).unwrap_or_else(|error| {
+                eprintln!("{}: {error}", options.bot.join(" "));
+                process::exit(1);
+            });
+            print!("{report}");
+            Ok(())
+        }
+        Command::Score { guess, answer } => {
+            commands::score(&guess, &answer);
+            Ok(())
+        }
+        Command::Stats => commands::stats(),
+    }
+}
+
+/// Word lists of `settings`, exiting when they can't be used: unlike the
+/// interactive game, commands run by scripts must not quietly play another
+/// list.
+fn load_config(settings: &Settings) -> Config {
+    Config::load(settings).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    })
+}
+
+/// Word lists of `settings` and their pattern matrix, for the solver.
+fn load_lists(settings: &Settings) -> (Config, Rc<PatternMatrix>) {
+    let config = load_config(settings);
+    let matrix = Rc::new(PatternMatrix::load_or_build(
+        &config,
+        paths::cache_dir().as_deref(),
+    ));
+    (config, matrix)
 }
@@ -1,71 +1,165 @@
-use crate::modules::revelation::{LetterRevelation, WordRevelation};
-use rand::rng;
-use rand::seq::IndexedRandom;
+use crate::modules::{
+    revelation::WordRevelation,
+    settings::Settings,
+    wordlist::{self, WordListError},
+};
+use rand::{Rng, rng};
+use std::{
+    collections::{BTreeSet, HashSet},
+    path::{Path, PathBuf},
+};
 
-#[derive(Default)]
+pub const FILE_PATH: &str = "./valid-wordle-words.txt";
+pub const ANSWERS_FILE_PATH: &str = "./wordle-answers.txt";
+pub const WORDS: &str = include_str!("../../valid-wordle-words.txt");
+pub const ANSWERS: &str = include_str!("../../wordle-answers.txt");
+/// Length of the built-in words.
+pub const WORD_LENGTH: usize = 5;
+/// Shortest words a list can have.
+pub const MIN_WORD_LENGTH: usize = 4;
+/// Longest words a list can have.
+pub const MAX_WORD_LENGTH: usize = 8;
+
+/// Word lists of a game and the answer to find.
+#[derive(Clone)]
 pub struct Config {
-    pub _file_path: String,
-    pub content: Vec<String>,
-    pub chosen_word: String,
+    file_path: Option<PathBuf>,
+    content: Vec<String>,
+    answers: Vec<String>,
+    chosen_word: String,
+    answer_index: usize,
+    word_length: usize,
+    alphabet: BTreeSet<char>,
 }
 
 impl Config {
-    pub fn new(file_path: String, contents: String) -> Config {
-        let words: Vec<String> = contents
-            .split_ascii_whitespace()
-            .map(|s| s.to_string())
+    /// Config accepting `words` and `answers` as guesses, with a random
+    /// answer. Fails when there are no answers, or when the words aren't all
+    /// as long, from `MIN_WORD_LENGTH` to `MAX_WORD_LENGTH` letters.
+    pub fn new(
+        file_path: Option<PathBuf>,
+        mut words: Vec<String>,
+        answers: Vec<String>,
+    ) -> Result<Config, WordListError> {
+        if answers.is_empty() {
+            return Err(WordListError::NoAnswers);
+        }
+        wordlist::common_length(words.iter().chain(&answers))?;
+        let known: HashSet<&String> = words.iter().collect();
+        let missing: Vec<String> = answers
+            .iter()
+            .filter(|answer| !known.contains(answer))
+            .cloned()
             .collect();
-        let mut rng = rng();
-        let chosen_word = words
-            .choose(&mut rng)
-            .expect("Couldn't Choose A Word")
-            .clone();
-        Config {
-            _file_path: file_path,
+        words.extend(missing);
+        let answer_index = rng().random_range(0..answers.len());
+        let chosen_word = answers[answer_index].clone();
+        let alphabet = words.iter().flat_map(|word| word.chars()).collect();
+        Ok(Config {
+            file_path,
+            alphabet,
             content: words,
-            chosen_word: chosen_word,
+            answers,
+            word_length: chosen_word.chars().count(),
+            chosen_word,
+            answer_index,
+        })
+    }
+
+    /// Config over the word lists compiled into the binary.
+    pub fn embedded() -> Config {
+        let words = wordlist::parse(Path::new(FILE_PATH), WORDS, WORD_LENGTH)
+            .expect("built-in word list is valid");
+        let answers = wordlist::parse(Path::new(ANSWERS_FILE_PATH), ANSWERS, WORD_LENGTH)
+            .expect("built-in answer list is valid");
+        Config::new(None, words, answers).expect("built-in lists are valid")
+    }
+
+    /// Reads the word lists named in `settings`, using the built-in ones for
+    /// any that isn't set. A custom guess list without an answer list draws
+    /// its answers from the guesses.
+    pub fn load(settings: &Settings) -> Result<Config, WordListError> {
+        let word_length = settings.word_length;
+        let Some(words_path) = &settings.words_path else {
+            if word_length != WORD_LENGTH {
+                return Err(WordListError::NoBuiltIn { word_length });
+            }
+            let embedded = Config::embedded();
+            return match &settings.answers_path {
+                Some(path) => {
+                    Config::new(None, embedded.content, wordlist::load(path, word_length)?)
+                }
+                None => Ok(embedded),
+            };
+        };
+        let words = wordlist::load(words_path, word_length)?;
+        let answers = match &settings.answers_path {
+            Some(path) => wordlist::load(path, word_length)?,
+            None => words.clone(),
+        };
+        Config::new(Some(words_path.clone()), words, answers)
+    }
+
+    /// Makes `answers[index]` the word to find, wrapping around the list.
+    pub fn choose_answer(&mut self, index: usize) {
+        self.answer_index = index % self.answers.len();
+        self.chosen_word = self.answers[self.answer_index].clone();
+    }
+
+    /// Makes `word` the word to find, returning `false` when it isn't one of
+    /// the answers.
+    pub fn choose_word(&mut self, word: &str) -> bool {
+        match self.answers.iter().position(|answer| answer == word) {
+            Some(index) => {
+                self.choose_answer(index);
+                true
+            }
+            None => false,
         }
     }
-    fn get_correct_revelations(
-        &self,
-        guessed_word: &str,
-        true_word: &mut Vec<u8>,
-    ) -> Vec<LetterRevelation> {
-        guessed_word
-            .as_bytes()
-            .iter()
-            .enumerate()
-            .map(|c| LetterRevelation::get_correct(true_word, c.1, c.0))
-            .filter(|el| el.is_some())
-            .map(|el| el.unwrap())
-            .collect()
+
+    /// Where the allowed guesses were read from, `None` for the built-in list.
+    pub fn file_path(&self) -> Option<&Path> {
+        self.file_path.as_deref()
     }
-    fn get_incorrect_revelation(
-        &self,
-        guessed_word: &str,
-        true_word: &mut Vec<u8>,
-        correct_revelations: &Vec<LetterRevelation>,
-    ) -> Vec<LetterRevelation> {
-        guessed_word
-            .as_bytes()
-            .iter()
-            .enumerate()
-            .filter(|(index, _)| !correct_revelations.iter().any(|x| x.index == *index))
-            .map(|c| LetterRevelation::get_incorrect(true_word, c.1, c.0))
-            .collect()
+
+    /// Every word accepted as a guess, answers included.
+    pub fn content(&self) -> &[String] {
+        &self.content
+    }
+
+    /// Curated words the answer is picked from.
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    /// The answer of the game.
+    pub fn chosen_word(&self) -> &str {
+        &self.chosen_word
+    }
+
+    /// Position of the answer in `answers`.
+    pub fn answer_index(&self) -> usize {
+        self.answer_index
+    }
+
+    /// Letters in every word.
+    pub fn word_length(&self) -> usize {
+        self.word_length
+    }
+
+    /// Every letter appearing in the allowed guesses, the only ones that can
+    /// be typed.
+    pub fn alphabet(&self) -> &BTreeSet<char> {
+        &self.alphabet
     }
 
+    /// Feedback for `guessed_word` against the answer.
     pub fn check(&self, guessed_word: &str) -> WordRevelation {
-        let mut true_word = self.chosen_word.as_bytes().to_vec().clone();
-        let correct_revelations = self.get_correct_revelations(guessed_word, &mut true_word);
-        let else_revelations: Vec<LetterRevelation> =
-            self.get_incorrect_revelation(guessed_word, &mut true_word, &correct_revelations);
-
-        let mut revelations = [correct_revelations, else_revelations].concat();
-        revelations.sort_by(|a, b| a.index.cmp(&b.index));
-        revelations.into_iter().collect()
+        WordRevelation::compute(guessed_word, &self.chosen_word)
     }
 
+    /// Whether `guessed_word` is an accepted guess.
     pub fn word_exists(&self, guessed_word: &str) -> bool {
         self.content.iter().any(|f| f == guessed_word)
     }
@@ -0,0 +1,77 @@
+//! The word of the day, the same for everyone with the same answer list.
+
+use time::{Date, Month, OffsetDateTime};
+
+/// Mixed into the answer order so it can't be guessed from the list alone.
+/// Changing it changes every future daily word.
+const SEED: u64 = 0x5745_4c59_2d44_4159;
+
+/// Local date, or the UTC one when the local offset can't be determined.
+pub fn today() -> Date {
+    OffsetDateTime::now_local()
+        .unwrap_or_else(|_| OffsetDateTime::now_utc())
+        .date()
+}
+
+/// Puzzle number of `date`, counted from the first Wordle on 2021-06-19.
+pub fn puzzle_number(date: Date) -> i64 {
+    (date - epoch()).whole_days()
+}
+
+/// Date of puzzle `number`.
+pub fn date_of(number: i64) -> Date {
+    epoch() + time::Duration::days(number)
+}
+
+fn epoch() -> Date {
+    Date::from_calendar_date(2021, Month::June, 19).expect("valid date")
+}
+
+/// Index of the answer of `date` in a list of `answers` words, `None` for an
+/// empty list. Days walk through a fixed permutation of the list, so no word
+/// comes back before every other one has been played.
+pub fn answer_index(date: Date, answers: usize) -> Option<usize> {
+    let n = answers as u64;
+    if n == 0 {
+        return None;
+    }
+    let mut step = splitmix64(SEED) % n;
+    while gcd(step, n) != 1 {
+        step = (step + 1) % n;
+    }
+    let offset = splitmix64(SEED.wrapping_add(1)) % n;
+    let day = puzzle_number(date).rem_euclid(n as i64) as u64;
+    Some(((step as u128 * day as u128 + offset as u128) % n as u128) as usize)
+}
+
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn answers_cycle_through_the_list() {
+        let first = Date::from_calendar_date(2026, Month::January, 1).unwrap();
+        assert_eq!(puzzle_number(first), 1657);
+        assert_eq!(date_of(1657), first);
+        let days: Vec<usize> = (0..2316)
+            .filter_map(|day| answer_index(first + time::Duration::days(day), 2316))
+            .collect();
+        assert_eq!(days.iter().collect::<HashSet<_>>().len(), 2316);
+        assert_eq!(answer_index(first, 2316), Some(days[0]));
+        assert_eq!(answer_index(first, 1), Some(0));
+        assert_eq!(answer_index(first, 0), None);
+    }
+}
@@ -0,0 +1,400 @@
+use crate::modules::{
+    config::Config,
+    pattern::PatternMatrix,
+    puzzle::Puzzle,
+    revelation::WordRevelation,
+    settings::Settings,
+    solver::{HintViolation, Solver},
+    state::State,
+};
+
+use std::rc::Rc;
+
+use rand::{Rng, rng};
+
+/// Something the player does.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    /// Types a letter at the end of the guess.
+    Type(char),
+    /// Removes the last letter of the guess.
+    Delete,
+    /// Plays the typed guess.
+    Submit,
+    /// Starts over with a new random answer from the same lists.
+    Restart,
+}
+
+/// What an action led to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    /// The guess being typed changed.
+    Edited,
+    /// Nothing happened: the game is over, the guess is already full or
+    /// empty, or the key is not a letter.
+    Ignored,
+    /// The letter is not used by the word list.
+    NotInAlphabet(char),
+    /// The guess was scored and the game goes on.
+    Accepted,
+    /// The guess doesn't have as many letters as the answer.
+    InvalidLength {
+        /// Letters in the answer.
+        expected: usize,
+    },
+    /// The guess is not in the word list.
+    UnknownWord,
+    /// The guess ignores a revealed hint in hard mode.
+    HardMode(HintViolation),
+    /// The guess was the answer.
+    Won {
+        /// Guesses it took, this one included.
+        guesses: usize,
+    },
+    /// The last attempt was used without finding the answer.
+    Lost {
+        /// The word that was to be found.
+        answer: String,
+    },
+    /// A new game started.
+    Restarted,
+}
+
+impl Outcome {
+    /// What to tell the player when the action was rejected, `None` for
+    /// every other outcome.
+    pub fn message(&self) -> Option<String> {
+        match self {
+            Outcome::NotInAlphabet(c) => Some(format!("'{c}' is not used by this word list")),
+            Outcome::InvalidLength { expected } => {
+                Some(format!("Word must be of {expected} letters"))
+            }
+            Outcome::UnknownWord => Some(String::from("Word doesn't exist in dictionary")),
+            Outcome::HardMode(violation) => Some(format!("Hard mode: {violation}")),
+            _ => None,
+        }
+    }
+}
+
+/// Whether a game is still going.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Status {
+    /// Guesses are still accepted.
+    #[default]
+    Playing,
+    /// The answer was found.
+    Won,
+    /// Every attempt was used.
+    Lost,
+}
+
+/// The rules of a game, driven by [`Action`]s whatever the front end.
+pub struct Engine {
+    config: Config,
+    settings: Settings,
+    solver: Solver,
+    /// Kept to be attached again to the solver of a restarted game.
+    matrix: Option<Rc<PatternMatrix>>,
+    guess: String,
+    revelations: Vec<WordRevelation>,
+    status: Status,
+}
+
+impl Engine {
+    /// Game finding the answer of `config` under the rules of `settings`.
+    pub fn new(config: Config, settings: Settings) -> Engine {
+        let mut solver = Solver::with_guesses(config.answers().to_vec(), config.content().to_vec());
+        solver.set_hard_mode(settings.hard_mode);
+        Engine {
+            solver,
+            config,
+            settings,
+            matrix: None,
+            guess: String::new(),
+            revelations: vec![],
+            status: Status::Playing,
+        }
+    }
+
+    /// Plays `action` and tells what came of it.
+    pub fn apply(&mut self, action: Action) -> Outcome {
+        match action {
+            Action::Restart => self.restart(),
+            _ if self.is_over() => Outcome::Ignored,
+            Action::Type(c) => self.type_letter(c),
+            Action::Delete => match self.guess.pop() {
+                Some(_) => Outcome::Edited,
+                None => Outcome::Ignored,
+            },
+            Action::Submit => self.submit(),
+        }
+    }
+
+    /// Plays `word` as a whole guess, dropping whatever was being typed.
+    pub fn play(&mut self, word: &str) -> Outcome {
+        if self.is_over() {
+            return Outcome::Ignored;
+        }
+        self.guess.clear();
+        if word.chars().count() != self.config.word_length() {
+            return Outcome::InvalidLength {
+                expected: self.config.word_length(),
+            };
+        }
+        for c in word.chars() {
+            match self.type_letter(c) {
+                Outcome::Edited => {}
+                outcome => {
+                    self.guess.clear();
+                    return match outcome {
+                        Outcome::Ignored => Outcome::NotInAlphabet(c),
+                        outcome => outcome,
+                    };
+                }
+            }
+        }
+        let outcome = self.submit();
+        self.guess.clear();
+        outcome
+    }
+
+    /// Types `c` lowercased, provided it is a letter of the word list.
+    fn type_letter(&mut self, c: char) -> Outcome {
+        let mut lowercase = c.to_lowercase();
+        let letter = match (lowercase.next(), lowercase.next()) {
+            (Some(letter), None) => letter,
+            _ => return Outcome::Ignored,
+        };
+        if !self.config.alphabet().contains(&letter) {
+            return if c.is_whitespace() || c.is_control() {
+                Outcome::Ignored
+            } else {
+                Outcome::NotInAlphabet(c)
+            };
+        }
+        if self.guess.chars().count() >= self.config.word_length() {
+            return Outcome::Ignored;
+        }
+        self.guess.push(letter);
+        Outcome::Edited
+    }
+
+    fn submit(&mut self) -> Outcome {
+        if self.guess.chars().count() != self.config.word_length() {
+            return Outcome::InvalidLength {
+                expected: self.config.word_length(),
+            };
+        }
+        if !self.config.word_exists(&self.guess) {
+            return Outcome::UnknownWord;
+        }
+        if self.settings.hard_mode
+            && let Some(violation) = self.solver.hard_mode_violation(&self.guess)
+        {
+            return Outcome::HardMode(violation);
+        }
+        let revelation = self.config.check(&self.guess);
+        self.solver.add_revelations(&revelation);
+        let won = (&revelation).into_iter().all(|r| r.state == State::Correct);
+        self.revelations.push(revelation);
+        self.guess.clear();
+        if won {
+            self.status = Status::Won;
+            Outcome::Won {
+                guesses: self.attempts(),
+            }
+        } else if Some(self.attempts()) == self.settings.max_attempts {
+            self.status = Status::Lost;
+            Outcome::Lost {
+                answer: self.config.chosen_word().to_string(),
+            }
+        } else {
+            Outcome::Accepted
+        }
+    }
+
+    fn restart(&mut self) -> Outcome {
+        let mut config = self.config.clone();
+        config.choose_answer(rng().random_range(0..config.answers().len()));
+        let mut settings = self.settings.clone();
+        // a shared puzzle is only played once, the next game is a new word
+        settings.puzzle = Puzzle::Random;
+        let matrix = self.matrix.take();
+        *self = Engine::new(config, settings);
+        if let Some(matrix) = matrix {
+            self.attach_matrix(matrix);
+        }
+        Outcome::Restarted
+    }
+
+    /// Lets the solver rank hints through a precomputed pattern matrix.
+    pub fn attach_matrix(&mut self, matrix: Rc<PatternMatrix>) {
+        self.solver.attach_matrix(matrix.clone());
+        self.matrix = Some(matrix);
+    }
+
+    /// Word lists and answer of the game.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Rules the game is played with.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Solver fed with every accepted guess, for hints.
+    pub fn solver(&self) -> &Solver {
+        &self.solver
+    }
+
+    /// Whether a pattern matrix was attached.
+    pub fn has_matrix(&self) -> bool {
+        self.matrix.is_some()
+    }
+
+    /// The guess being typed.
+    pub fn guess(&self) -> &str {
+        &self.guess
+    }
+
+    /// Feedback of every accepted guess, oldest first.
+    pub fn revelations(&self) -> &[WordRevelation] {
+        &self.revelations
+    }
+
+    /// Number of accepted guesses.
+    pub fn attempts(&self) -> usize {
+        self.revelations.len()
+    }
+
+    /// Whether the game is going, won or lost.
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Whether the game is won or lost.
+    pub fn is_over(&self) -> bool {
+        self.status != Status::Playing
+    }
+
+    /// How the game ended, such as `Solved in 3/6`, or `None` while it goes
+    /// on.
+    pub fn summary(&self) -> Option<String> {
+        let guesses = self.attempts();
+        match (self.status, self.settings.max_attempts) {
+            (Status::Playing, _) => None,
+            (Status::Won, Some(max)) => Some(format!("Solved in {guesses}/{max}")),
+            (Status::Won, None) => Some(format!("Solved in {guesses}")),
+            (Status::Lost, _) => Some(format!("The true word was: {}", self.config.chosen_word())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn engine(answer: usize, settings: Settings) -> Engine {
+        let words: Vec<String> = ["crane", "caper", "slate", "pious", "plate"]
+            .map(String::from)
+            .into();
+        let mut config = Config::new(None, words.clone(), words).unwrap();
+        config.choose_answer(answer);
+        Engine::new(config, settings)
+    }
+
+    fn play(engine: &mut Engine, word: &str) -> Outcome {
+        for c in word.chars() {
+            engine.apply(Action::Type(c));
+        }
+        engine.apply(Action::Submit)
+    }
+
+    #[test]
+    fn actions_report_what_happened() {
+        let mut engine = engine(1, Settings::default());
+        assert_eq!(engine.apply(Action::Delete), Outcome::Ignored);
+        assert_eq!(engine.apply(Action::Type('Q')), Outcome::NotInAlphabet('Q'));
+        assert_eq!(engine.apply(Action::Type('C')), Outcome::Edited);
+        assert_eq!(engine.guess(), "c");
+        assert_eq!(
+            engine.apply(Action::Submit),
+            Outcome::InvalidLength { expected: 5 }
+        );
+        assert_eq!(play(&mut engine, "rate"), Outcome::UnknownWord);
+        assert_eq!(Outcome::Accepted.message(), None);
+        assert_eq!(engine.summary(), None);
+        for _ in 0..5 {
+            engine.apply(Action::Delete);
+        }
+        assert_eq!(play(&mut engine, "crane"), Outcome::Accepted);
+        assert_eq!(play(&mut engine, "caper"), Outcome::Won { guesses: 2 });
+        assert_eq!(engine.status(), Status::Won);
+        assert_eq!(engine.summary().as_deref(), Some("Solved in 2/6"));
+        assert_eq!(engine.apply(Action::Type('a')), Outcome::Ignored);
+        assert_eq!(engine.play("crane"), Outcome::Ignored);
+        assert_eq!(engine.apply(Action::Restart), Outcome::Restarted);
+        assert_eq!(
+            engine.play("cranes"),
+            Outcome::InvalidLength { expected: 5 }
+        );
+        assert_eq!(engine.play("cr ne"), Outcome::NotInAlphabet(' '));
+        assert_eq!(engine.guess(), "");
+        assert_eq!(engine.attempts(), 0);
+        assert_eq!(engine.status(), Status::Playing);
+    }
+
+    #[test]
+    fn games_are_lost_at_the_attempt_limit() {
+        let settings = Settings {
+            hard_mode: true,
+            max_attempts: Some(2),
+            ..Settings::default()
+        };
+        let mut engine = engine(3, settings);
+        assert_eq!(play(&mut engine, "caper"), Outcome::Accepted);
+        assert_eq!(
+            play(&mut engine, "crane"),
+            Outcome::HardMode(HintViolation::Missing {
+                letter: 'p',
+                count: 1
+            })
+        );
+        assert!(engine.apply(Action::Submit).message().is_some());
+        for _ in 0..5 {
+            engine.apply(Action::Delete);
+        }
+        assert_eq!(
+            play(&mut engine, "plate"),
+            Outcome::Lost {
+                answer: String::from("pious")
+            }
+        );
+        assert_eq!(
+            engine.summary().as_deref(),
+            Some("The true word was: pious")
+        );
+    }
+
+    #[test]
+    fn hard_mode_hints_can_be_played() {
+        let words: Vec<String> = ["crane", "caper", "cater", "caber", "stomp"]
+            .map(String::from)
+            .into();
+        let mut config = Config::new(None, words.clone(), words).unwrap();
+        config.choose_answer(1);
+        let settings = Settings {
+            hard_mode: true,
+            ..Settings::default()
+        };
+        let mut engine = Engine::new(config, settings);
+        assert_eq!(engine.play("crane"), Outcome::Accepted);
+        let hint = engine.solver().best_guesses(1)[0].word.clone();
+        assert_ne!(hint, "stomp");
+        assert!(matches!(
+            engine.play(&hint),
+            Outcome::Accepted | Outcome::Won { .. }
+        ));
+    }
+}
@@ -1,5 +1,11 @@
 pub mod config;
-pub mod game;
+pub mod daily;
+pub mod engine;
+pub mod pattern;
+pub mod protocol;
+pub mod puzzle;
 pub mod revelation;
+pub mod settings;
 pub mod solver;
 pub mod state;
+pub mod wordlist;
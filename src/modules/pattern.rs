@@ -0,0 +1,306 @@
+use crate::modules::{
+    config::{Config, MAX_WORD_LENGTH},
+    revelation::WordRevelation,
+    state::State,
+    wordlist::{self, WordListError},
+};
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+};
+
+const CACHE_MAGIC: &[u8; 4] = b"WPM2";
+
+/// Feedback for a whole guess packed in base 3, one digit per letter with the
+/// first letter as the least significant digit (0 wrong, 1 change, 2 correct).
+/// Eight letters need 3^8 = 6561 values, hence the `u16`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pattern(pub u16);
+
+impl Pattern {
+    /// Number of distinct patterns for words of `word_length` letters.
+    pub fn count(word_length: usize) -> usize {
+        3usize.pow(word_length as u32)
+    }
+
+    /// Pattern of a guess that is the answer.
+    pub fn solved(word_length: usize) -> Pattern {
+        Pattern((Pattern::count(word_length) - 1) as u16)
+    }
+
+    /// Same scoring as `WordRevelation::compute`, without allocating.
+    /// `None` unless both words have the same number of letters, at most
+    /// `MAX_WORD_LENGTH`.
+    pub fn compute(guess: &str, answer: &str) -> Option<Pattern> {
+        let word_length = guess.chars().count();
+        if word_length > MAX_WORD_LENGTH || answer.chars().count() != word_length {
+            return None;
+        }
+        let mut guessed = ['\0'; MAX_WORD_LENGTH];
+        let mut true_word = ['\0'; MAX_WORD_LENGTH];
+        for (i, (g, a)) in guess.chars().zip(answer.chars()).enumerate() {
+            guessed[i] = g;
+            true_word[i] = a;
+        }
+        let mut digits = [0u16; MAX_WORD_LENGTH];
+        let mut used = [false; MAX_WORD_LENGTH];
+        for i in 0..word_length {
+            if guessed[i] == true_word[i] {
+                digits[i] = 2;
+                used[i] = true;
+            }
+        }
+        for i in 0..word_length {
+            if digits[i] != 0 {
+                continue;
+            }
+            if let Some(j) = (0..word_length).find(|&j| !used[j] && true_word[j] == guessed[i]) {
+                digits[i] = 1;
+                used[j] = true;
+            }
+        }
+        Some(Pattern::from_digits(&digits[..word_length]))
+    }
+
+    fn from_digits(digits: &[u16]) -> Pattern {
+        Pattern(digits.iter().rev().fold(0, |acc, d| acc * 3 + d))
+    }
+
+    /// The pattern as an index, below `Pattern::count`.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    /// Whether every letter is correct.
+    pub fn is_solved(self, word_length: usize) -> bool {
+        self == Pattern::solved(word_length)
+    }
+
+    /// Pattern of `revelation`, `None` past `MAX_WORD_LENGTH` letters.
+    pub fn from_revelation(revelation: &WordRevelation) -> Option<Pattern> {
+        if revelation.len() > MAX_WORD_LENGTH {
+            return None;
+        }
+        let digits: Vec<u16> = revelation
+            .into_iter()
+            .map(|r| match r.state {
+                State::Wrong => 0,
+                State::Change => 1,
+                State::Correct => 2,
+            })
+            .collect();
+        Some(Pattern::from_digits(&digits))
+    }
+}
+
+/// Feedback of every allowed guess against every possible answer, stored row
+/// by row so ranking a guess only walks one contiguous slice.
+pub struct PatternMatrix {
+    guesses: Vec<String>,
+    answers: Vec<String>,
+    guess_index: HashMap<String, usize>,
+    answer_index: HashMap<String, usize>,
+    patterns: Vec<Pattern>,
+}
+
+impl PatternMatrix {
+    /// Computes the pattern of every guess against every answer. Fails
+    /// unless the words are all as long, from `MIN_WORD_LENGTH` to
+    /// `MAX_WORD_LENGTH` letters.
+    pub fn new(guesses: Vec<String>, answers: Vec<String>) -> Result<PatternMatrix, WordListError> {
+        wordlist::common_length(guesses.iter().chain(&answers))?;
+        Ok(PatternMatrix::build(guesses, answers))
+    }
+
+    /// Matrix of the words of `config`, read from `cache_dir` when a file for
+    /// the same word lists exists, otherwise computed and written there if
+    /// possible.
+    pub fn load_or_build(config: &Config, cache_dir: Option<&Path>) -> PatternMatrix {
+        let guesses = config.content().to_vec();
+        let answers = config.answers().to_vec();
+        let Some(path) = cache_dir.map(|dir| dir.join(cache_file_name(&guesses, &answers))) else {
+            return PatternMatrix::build(guesses, answers);
+        };
+        let expected_len = guesses.len() * answers.len();
+        if let Ok(patterns) = read_cache(&path, expected_len, config.word_length()) {
+            return PatternMatrix::from_parts(guesses, answers, patterns);
+        }
+        let matrix = PatternMatrix::build(guesses, answers);
+        // the cache is only an optimisation, a read-only disk is fine
+        let _ = matrix.write_cache(&path);
+        matrix
+    }
+
+    /// Computes the matrix of words already checked to be as long.
+    fn build(guesses: Vec<String>, answers: Vec<String>) -> PatternMatrix {
+        let patterns = guesses
+            .iter()
+            .flat_map(|guess| {
+                answers
+                    .iter()
+                    .map(move |answer| Pattern::compute(guess, answer).expect("words are checked"))
+            })
+            .collect();
+        PatternMatrix::from_parts(guesses, answers, patterns)
+    }
+
+    fn from_parts(
+        guesses: Vec<String>,
+        answers: Vec<String>,
+        patterns: Vec<Pattern>,
+    ) -> PatternMatrix {
+        let index = |words: &Vec<String>| {
+            words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| (w.clone(), i))
+                .collect()
+        };
+        PatternMatrix {
+            guess_index: index(&guesses),
+            answer_index: index(&answers),
+            guesses,
+            answers,
+            patterns,
+        }
+    }
+
+    /// Words of the rows.
+    pub fn guesses(&self) -> &[String] {
+        &self.guesses
+    }
+
+    /// Words of the columns.
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    /// Row of `word`, `None` if it is not a guess.
+    pub fn guess_index(&self, word: &str) -> Option<usize> {
+        self.guess_index.get(word).copied()
+    }
+
+    /// Column of `word`, `None` if it is not an answer.
+    pub fn answer_index(&self, word: &str) -> Option<usize> {
+        self.answer_index.get(word).copied()
+    }
+
+    /// Patterns of guess number `guess` against every answer.
+    pub fn row(&self, guess: usize) -> &[Pattern] {
+        let width = self.answers.len();
+        &self.patterns[guess * width..(guess + 1) * width]
+    }
+
+    /// Pattern of guess number `guess` against answer number `answer`.
+    pub fn get(&self, guess: usize, answer: usize) -> Pattern {
+        self.row(guess)[answer]
+    }
+
+    fn write_cache(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let bytes: Vec<u8> = self
+            .patterns
+            .iter()
+            .flat_map(|p| p.0.to_le_bytes())
+            .collect();
+        let mut file = fs::File::create(path)?;
+        file.write_all(CACHE_MAGIC)?;
+        file.write_all(&bytes)
+    }
+}
+
+/// Patterns stored at `path`, rejecting files of another size or holding a
+/// value no word of `word_length` letters can get.
+fn read_cache(path: &Path, expected_len: usize, word_length: usize) -> io::Result<Vec<Pattern>> {
+    let stale = || io::Error::new(io::ErrorKind::InvalidData, "stale pattern cache");
+    let mut file = fs::File::open(path)?;
+    let mut magic = [0u8; 4];
+    file.read_exact(&mut magic)?;
+    let mut bytes = Vec::with_capacity(expected_len * 2);
+    file.read_to_end(&mut bytes)?;
+    if &magic != CACHE_MAGIC || bytes.len() != expected_len * 2 {
+        return Err(stale());
+    }
+    bytes
+        .chunks_exact(2)
+        .map(|pair| Pattern(u16::from_le_bytes([pair[0], pair[1]])))
+        .map(|pattern| {
+            (pattern.index() < Pattern::count(word_length))
+                .then_some(pattern)
+                .ok_or_else(stale)
+        })
+        .collect()
+}
+
+/// Names the cache after an FNV-1a hash of both word lists so editing a list
+/// never picks up patterns computed for another one.
+fn cache_file_name(guesses: &[String], answers: &[String]) -> PathBuf {
+    let hash = wordlist::hash(guesses.iter().chain([String::new()].iter()).chain(answers));
+    PathBuf::from(format!("patterns-{hash:016x}.bin"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_matches_revelation() {
+        let lists = [
+            ["speed", "erase", "abide", "eerie", "sheep", "geese"],
+            ["élève", "lèvre", "rêver", "crème", "évier", "éleve"],
+        ];
+        for words in lists {
+            for guess in words {
+                for answer in words {
+                    let revelation = WordRevelation::compute(guess, answer);
+                    assert_eq!(
+                        Pattern::compute(guess, answer),
+                        Pattern::from_revelation(&revelation),
+                        "{guess} against {answer}"
+                    );
+                }
+            }
+        }
+        assert_eq!(
+            Pattern::compute("élève", "lèvre"),
+            Some(Pattern::from_digits(&[0, 1, 1, 1, 2]))
+        );
+        for word in ["geese", "élève", "abacuses"] {
+            let length = word.chars().count();
+            assert_eq!(Pattern::compute(word, word), Some(Pattern::solved(length)));
+        }
+        assert_eq!(Pattern::solved(5).index(), Pattern::count(5) - 1);
+        assert_eq!(Pattern::compute("abcdefghi", "abcdefghi"), None);
+        assert_eq!(Pattern::compute("crane", "cranes"), None);
+        assert!(PatternMatrix::new(vec![String::from("abcdefghi")], vec![]).is_err());
+    }
+
+    #[test]
+    fn matrix_survives_cache() {
+        let words: Vec<String> = ["speed", "erase", "abide"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        let config = Config::new(None, words.clone(), words).unwrap();
+        let dir = std::env::temp_dir().join(format!("wordle-test-{}", std::process::id()));
+        let built = PatternMatrix::load_or_build(&config, Some(&dir));
+        let loaded = PatternMatrix::load_or_build(&config, Some(&dir));
+        assert_eq!(built.patterns, loaded.patterns);
+        assert_eq!(Some(loaded.get(0, 1)), Pattern::compute("speed", "erase"));
+
+        // an out of range pattern means the file is corrupt, so it is rebuilt
+        let path = dir.join(cache_file_name(&built.guesses, &built.answers));
+        let mut bytes = fs::read(&path).unwrap();
+        bytes[CACHE_MAGIC.len()..][..2].copy_from_slice(&u16::MAX.to_le_bytes());
+        fs::write(&path, bytes).unwrap();
+        let rebuilt = PatternMatrix::load_or_build(&config, Some(&dir));
+        assert_eq!(built.patterns, rebuilt.patterns);
+        assert!(read_cache(&path, 9, 5).is_ok());
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,212 @@
+//! Line protocol between a game host and a bot playing through its stdin and
+//! stdout, one message per line:
+//!
+//! ```text
+//! host: wordle 1            protocol version
+//! bot:  ready [NAME]
+//! host: game 5 6            word length and attempts, `zen` for no limit
+//! bot:  guess crane
+//! host: feedback BYBGB      G green, Y yellow, B black
+//! bot:  guess ...
+//! host: invalid REASON      a rejected guess loses the game
+//! host: result won 4 caper  or `lost`, with the guesses made and the answer,
+//!                           after the last feedback or `invalid`
+//! host: game ... | quit
+//! ```
+//!
+//! A bot answers every `game` and every `feedback` that isn't followed by a
+//! `result` with one guess. In `zen` games the host gives up on a bot that
+//! hasn't found the answer in [`ZEN_GUESS_LIMIT`] guesses, sending
+//! `result lost`.
+
+use crate::modules::revelation::WordRevelation;
+
+use std::{fmt, str::FromStr};
+
+/// Version sent in the greeting, bumped on any incompatible change.
+pub const VERSION: u32 = 1;
+
+/// Guesses after which a `zen` game is lost.
+pub const ZEN_GUESS_LIMIT: usize = 100;
+
+/// Lines sent by the host.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HostMessage {
+    /// First line of a session, `wordle VERSION`.
+    Hello {
+        /// Protocol version the host speaks.
+        version: u32,
+    },
+    /// A new game starts, the bot answers with its first guess.
+    Game {
+        /// Letters in the answer and every guess.
+        word_length: usize,
+        /// Guesses allowed, `None` in zen mode.
+        max_attempts: Option<usize>,
+    },
+    /// Feedback of the last guess, one `G`, `Y` or `B` per letter.
+    Feedback(String),
+    /// The last guess was rejected, with the reason, and the game is lost.
+    Invalid(String),
+    /// The game is over.
+    Result {
+        /// Whether the bot found the answer.
+        won: bool,
+        /// Guesses accepted in the game.
+        guesses: usize,
+        /// The word to find.
+        answer: String,
+    },
+    /// The session is over, the bot should exit.
+    Quit,
+}
+
+impl HostMessage {
+    /// `feedback` line for `revelation`.
+    pub fn feedback(revelation: &WordRevelation) -> HostMessage {
+        HostMessage::Feedback(revelation.to_string().replace(' ', ""))
+    }
+}
+
+impl fmt::Display for HostMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HostMessage::Hello { version } => write!(f, "wordle {version}"),
+            HostMessage::Game {
+                word_length,
+                max_attempts: Some(max),
+            } => write!(f, "game {word_length} {max}"),
+            HostMessage::Game { word_length, .. } => write!(f, "game {word_length} zen"),
+            HostMessage::Feedback(feedback) => write!(f, "feedback {feedback}"),
+            HostMessage::Invalid(reason) => write!(f, "invalid {reason}"),
+            HostMessage::Result {
+                won,
+                guesses,
+                answer,
+            } => {
+                let outcome = if *won { "won" } else { "lost" };
+                write!(f, "result {outcome} {guesses} {answer}")
+            }
+            HostMessage::Quit => write!(f, "quit"),
+        }
+    }
+}
+
+impl FromStr for HostMessage {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<HostMessage, String> {
+        let invalid = || format!("unexpected host message `{line}`");
+        let number = |field: &str| field.parse().map_err(|_| invalid());
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields.as_slice() {
+            ["wordle", version] => Ok(HostMessage::Hello {
+                version: version.parse().map_err(|_| invalid())?,
+            }),
+            ["game", length, "zen"] => Ok(HostMessage::Game {
+                word_length: number(length)?,
+                max_attempts: None,
+            }),
+            ["game", length, max] => Ok(HostMessage::Game {
+                word_length: number(length)?,
+                max_attempts: Some(number(max)?),
+            }),
+            ["feedback", feedback] => Ok(HostMessage::Feedback(feedback.to_string())),
+            ["invalid", ..] => Ok(HostMessage::Invalid(
+                line.trim()["invalid".len()..].trim().to_string(),
+            )),
+            ["result", outcome @ ("won" | "lost"), guesses, answer] => Ok(HostMessage::Result {
+                won: *outcome == "won",
+                guesses: number(guesses)?,
+                answer: answer.to_string(),
+            }),
+            ["quit"] => Ok(HostMessage::Quit),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Lines sent by the bot.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BotMessage {
+    /// Answer to the greeting, `ready [NAME]`.
+    Ready {
+        /// Name to show in results, the rest of the line.
+        name: Option<String>,
+    },
+    /// The next guess of the current game.
+    Guess(String),
+}
+
+impl fmt::Display for BotMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BotMessage::Ready { name: Some(name) } => write!(f, "ready {name}"),
+            BotMessage::Ready { name: None } => write!(f, "ready"),
+            BotMessage::Guess(word) => write!(f, "guess {word}"),
+        }
+    }
+}
+
+impl FromStr for BotMessage {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<BotMessage, String> {
+        match line.trim().split_once(' ') {
+            None if line.trim() == "ready" => Ok(BotMessage::Ready { name: None }),
+            Some(("ready", name)) => Ok(BotMessage::Ready {
+                name: Some(name.trim().to_string()),
+            }),
+            Some(("guess", word)) if !word.trim().contains(' ') => {
+                Ok(BotMessage::Guess(word.trim().to_string()))
+            }
+            _ => Err(format!("unexpected bot message `{}`", line.trim())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_round_trip() {
+        let host = [
+            HostMessage::Hello { version: VERSION },
+            HostMessage::Game {
+                word_length: 5,
+                max_attempts: Some(6),
+            },
+            HostMessage::Game {
+                word_length: 7,
+                max_attempts: None,
+            },
+            HostMessage::feedback(&WordRevelation::compute("crane", "caper")),
+            HostMessage::Invalid(String::from("Word doesn't exist in dictionary")),
+            HostMessage::Result {
+                won: true,
+                guesses: 4,
+                answer: String::from("caper"),
+            },
+            HostMessage::Quit,
+        ];
+        for message in host {
+            assert_eq!(message.to_string().parse(), Ok(message));
+        }
+        assert_eq!(
+            HostMessage::feedback(&WordRevelation::compute("crane", "caper")).to_string(),
+            "feedback GYYBY"
+        );
+        for message in [
+            BotMessage::Ready { name: None },
+            BotMessage::Ready {
+                name: Some(String::from("entropy bot")),
+            },
+            BotMessage::Guess(String::from("crane")),
+        ] {
+            assert_eq!(message.to_string().parse(), Ok(message));
+        }
+        assert!("guess two words".parse::<BotMessage>().is_err());
+        assert!("hello".parse::<BotMessage>().is_err());
+    }
+}
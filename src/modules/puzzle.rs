@@ -0,0 +1,161 @@
+//! Which answer a game plays, and codes to share it.
+
+use crate::modules::{daily, wordlist};
+
+use std::{fmt, str::FromStr};
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use time::Date;
+
+/// Crockford's base 32, without the easily confused I, L, O and U.
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const CODE_LENGTH: usize = 13;
+const KEY: u64 = 0x2f1b_7a3c_95d4_e681;
+/// Odd, so multiplying by it can be undone.
+const MULTIPLIER: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Which answer a game plays.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Puzzle {
+    /// A new random word every game.
+    #[default]
+    Random,
+    /// The word of the day.
+    Daily,
+    /// Picked by a generator seeded with the value, reproducible with the
+    /// same build and word list.
+    Seed(u64),
+    /// The word of a shared code.
+    Code(PuzzleCode),
+}
+
+impl Puzzle {
+    /// Index of the answer to play among `answers`, `None` for a random one.
+    /// `date` is the day of a daily puzzle.
+    pub fn answer_index(self, answers: &[String], date: Date) -> Result<Option<usize>, String> {
+        if answers.is_empty() {
+            return Err(String::from("There are no answers to pick from"));
+        }
+        match self {
+            Puzzle::Random => Ok(None),
+            Puzzle::Daily => Ok(daily::answer_index(date, answers.len())),
+            Puzzle::Seed(seed) => Ok(Some(seeded_index(seed, answers.len()))),
+            Puzzle::Code(code) => match code.index_in(answers) {
+                Some(index) => Ok(Some(index)),
+                None => Err(format!("Puzzle {code} is for another word list")),
+            },
+        }
+    }
+}
+
+/// Index of the answer a game seeded with `seed` plays among `answers`.
+fn seeded_index(seed: u64, answers: usize) -> usize {
+    StdRng::seed_from_u64(seed).random_range(0..answers)
+}
+
+/// Shareable name of a puzzle: the answer index and the word list it indexes,
+/// scrambled so the code doesn't give the answer away.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PuzzleCode {
+    list: u32,
+    index: u32,
+}
+
+impl PuzzleCode {
+    /// Code of answer number `index` in `answers`.
+    pub fn new(answers: &[String], index: usize) -> PuzzleCode {
+        PuzzleCode {
+            list: list_id(answers),
+            index: index as u32,
+        }
+    }
+
+    /// Index of the answer in `answers`, `None` when the code was made for
+    /// another list.
+    pub fn index_in(&self, answers: &[String]) -> Option<usize> {
+        let index = self.index as usize;
+        (self.list == list_id(answers) && index < answers.len()).then_some(index)
+    }
+}
+
+fn list_id(answers: &[String]) -> u32 {
+    let hash = wordlist::hash(answers);
+    (hash >> 32) as u32 ^ hash as u32
+}
+
+/// Inverse of an odd `m` modulo 2^64, each Newton step doubling the correct
+/// bits from the 3 that `m` starts with.
+const fn inverse(m: u64) -> u64 {
+    let mut inverse = m;
+    let mut step = 0;
+    while step < 5 {
+        inverse = inverse.wrapping_mul(2u64.wrapping_sub(m.wrapping_mul(inverse)));
+        step += 1;
+    }
+    inverse
+}
+
+impl fmt::Display for PuzzleCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = ((self.list as u64) << 32 | self.index as u64) ^ KEY;
+        value = value.wrapping_mul(MULTIPLIER);
+        value ^= value >> 32;
+        let code: String = (0..CODE_LENGTH)
+            .rev()
+            .map(|digit| ALPHABET[(value >> (digit * 5) & 31) as usize] as char)
+            .collect();
+        f.write_str(&code)
+    }
+}
+
+impl FromStr for PuzzleCode {
+    type Err = String;
+
+    fn from_str(code: &str) -> Result<PuzzleCode, String> {
+        let invalid = || format!("`{code}` is not a puzzle code");
+        let digits: Vec<u8> = code
+            .bytes()
+            .filter(|byte| *byte != b'-')
+            .map(|byte| byte.to_ascii_uppercase())
+            .collect();
+        if digits.len() != CODE_LENGTH {
+            return Err(invalid());
+        }
+        let mut value: u128 = 0;
+        for digit in digits {
+            let digit = ALPHABET
+                .iter()
+                .position(|letter| *letter == digit)
+                .ok_or_else(invalid)?;
+            value = value << 5 | digit as u128;
+        }
+        let mut value = u64::try_from(value).map_err(|_| invalid())?;
+        value ^= value >> 32;
+        value = value.wrapping_mul(inverse(MULTIPLIER)) ^ KEY;
+        Ok(PuzzleCode {
+            list: (value >> 32) as u32,
+            index: value as u32,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_round_trip_for_their_own_list() {
+        let answers: Vec<String> = ["crane", "slate", "pious"].map(String::from).into();
+        let code = PuzzleCode::new(&answers, 2);
+        let text = code.to_string();
+        assert_eq!(text.len(), CODE_LENGTH);
+        assert_ne!(text, PuzzleCode::new(&answers, 1).to_string());
+        let parsed: PuzzleCode = text.to_lowercase().parse().unwrap();
+        assert_eq!(parsed, code);
+        assert_eq!(parsed.index_in(&answers), Some(2));
+        assert_eq!(parsed.index_in(&answers[..2]), None);
+        assert!("not-a-code".parse::<PuzzleCode>().is_err());
+        assert!("ZZZZZZZZZZZZZ".parse::<PuzzleCode>().is_err());
+        assert_eq!(seeded_index(42, 3), seeded_index(42, 3));
+    }
+}
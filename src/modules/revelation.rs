@@ -1,22 +1,29 @@
 use crate::modules::state::State;
 
+use std::fmt;
+
+/// Feedback for the letter at `index` of a guess.
 #[derive(Debug, Clone, PartialEq)]
 pub struct LetterRevelation {
+    /// Position of the letter in the guess.
     pub index: usize,
+    /// The guessed letter.
     pub letter: char,
+    /// How it compares with the answer.
     pub state: State,
 }
 
+/// Letters of the true word are `None` once a guessed letter has used them.
 impl LetterRevelation {
-    pub fn get_correct(
-        true_word: &mut [u8],
-        guessed_letter: &u8,
+    fn get_correct(
+        true_word: &mut [Option<char>],
+        guessed_letter: char,
         guessed_index: usize,
     ) -> Option<LetterRevelation> {
         let mut state = State::Wrong;
         let mut found_index = 0;
-        for i in 0..5 {
-            if true_word[i] == *guessed_letter && guessed_index == i {
+        for (i, letter) in true_word.iter().enumerate() {
+            if *letter == Some(guessed_letter) && guessed_index == i {
                 state = State::Correct;
                 found_index = i;
                 break;
@@ -24,91 +31,163 @@ impl LetterRevelation {
         }
         match state {
             State::Correct => {
-                true_word[found_index] = 0x20;
-                return Some(LetterRevelation {
-                    letter: *guessed_letter as char,
+                true_word[found_index] = None;
+                Some(LetterRevelation {
+                    letter: guessed_letter,
                     index: guessed_index,
                     state,
-                });
+                })
             }
-            _ => return None,
+            _ => None,
         }
     }
-    pub fn get_incorrect(
-        true_word: &mut [u8],
-        guessed_letter: &u8,
+    fn get_incorrect(
+        true_word: &mut [Option<char>],
+        guessed_letter: char,
         guessed_index: usize,
     ) -> LetterRevelation {
         let mut state = State::Wrong;
         let mut found_index = 0;
-        for i in 0..5 {
-            if true_word[i] == *guessed_letter && guessed_index != i {
+        for (i, letter) in true_word.iter().enumerate() {
+            if *letter == Some(guessed_letter) && guessed_index != i {
                 state = State::Change;
                 found_index = i;
                 break;
             }
         }
         if state == State::Change {
-            true_word[found_index] = 0x20;
+            true_word[found_index] = None;
         }
-        return LetterRevelation {
-            letter: *guessed_letter as char,
+        LetterRevelation {
+            letter: guessed_letter,
             index: guessed_index,
             state,
-        };
+        }
     }
 }
 
-pub struct WordRevelation(
-    LetterRevelation,
-    LetterRevelation,
-    LetterRevelation,
-    LetterRevelation,
-    LetterRevelation,
-);
+/// Feedback for a whole guess, one revelation per letter in order.
+pub struct WordRevelation(Vec<LetterRevelation>);
 
 impl WordRevelation {
-    pub fn new(v: Vec<LetterRevelation>) -> WordRevelation{
-        let mut iter = v.into_iter();
-
-        WordRevelation(
-            iter.next().expect("Iterator provided fewer than 5 items"),
-            iter.next().expect("Iterator provided fewer than 5 items"),
-            iter.next().expect("Iterator provided fewer than 5 items"),
-            iter.next().expect("Iterator provided fewer than 5 items"),
-            iter.next().expect("Iterator provided fewer than 5 items"),
+    /// Revelation made of the letter revelations `v`, in order.
+    pub fn new(v: Vec<LetterRevelation>) -> WordRevelation {
+        WordRevelation(v)
+    }
+
+    /// Number of letters.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no letters.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Scores `guessed_word` against `true_word`: exact matches are resolved
+    /// first, then the remaining letters are marked as misplaced or wrong.
+    pub fn compute(guessed_word: &str, true_word: &str) -> WordRevelation {
+        let mut true_word: Vec<Option<char>> = true_word.chars().map(Some).collect();
+        let correct_revelations: Vec<LetterRevelation> = guessed_word
+            .chars()
+            .enumerate()
+            .filter_map(|c| LetterRevelation::get_correct(&mut true_word, c.1, c.0))
+            .collect();
+        let else_revelations: Vec<LetterRevelation> = guessed_word
+            .chars()
+            .enumerate()
+            .filter(|(index, _)| !correct_revelations.iter().any(|x| x.index == *index))
+            .map(|c| LetterRevelation::get_incorrect(&mut true_word, c.1, c.0))
+            .collect();
+
+        let mut revelations = [correct_revelations, else_revelations].concat();
+        revelations.sort_by_key(|r| r.index);
+        revelations.into_iter().collect()
+    }
+
+    /// Reads feedback written one `G` (green), `Y` (yellow) or `B` (black)
+    /// per letter of `guess`, spaces allowed, as printed by `Display`.
+    pub fn from_feedback(guess: &str, feedback: &str) -> Option<WordRevelation> {
+        let states: Vec<State> = feedback
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c.to_ascii_uppercase() {
+                'G' => Some(State::Correct),
+                'Y' => Some(State::Change),
+                'B' => Some(State::Wrong),
+                _ => None,
+            })
+            .collect::<Option<_>>()?;
+        if states.len() != guess.chars().count() {
+            return None;
+        }
+        Some(
+            guess
+                .chars()
+                .zip(states)
+                .enumerate()
+                .map(|(index, (letter, state))| LetterRevelation {
+                    index,
+                    letter,
+                    state,
+                })
+                .collect(),
         )
     }
 }
 
+/// Feedback as `B Y B G B`.
+impl fmt::Display for WordRevelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letters: Vec<&str> = self
+            .0
+            .iter()
+            .map(|r| match r.state {
+                State::Correct => "G",
+                State::Change => "Y",
+                State::Wrong => "B",
+            })
+            .collect();
+        f.write_str(&letters.join(" "))
+    }
+}
+
 impl FromIterator<LetterRevelation> for WordRevelation {
     fn from_iter<T: IntoIterator<Item = LetterRevelation>>(iter: T) -> Self {
-        let mut iter = iter.into_iter();
-
-        Self(
-            iter.next().expect("Iterator provided fewer than 5 items"),
-            iter.next().expect("Iterator provided fewer than 5 items"),
-            iter.next().expect("Iterator provided fewer than 5 items"),
-            iter.next().expect("Iterator provided fewer than 5 items"),
-            iter.next().expect("Iterator provided fewer than 5 items"),
-        )
+        Self(iter.into_iter().collect())
     }
 }
 
 impl IntoIterator for WordRevelation {
     type Item = LetterRevelation;
-    type IntoIter = std::array::IntoIter<Self::Item, 5>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        [self.0, self.1, self.2, self.3, self.4].into_iter()
+        self.0.into_iter()
     }
 }
 
 impl<'a> IntoIterator for &'a WordRevelation {
     type Item = &'a LetterRevelation;
-    type IntoIter = std::array::IntoIter<Self::Item, 5>;
+    type IntoIter = std::slice::Iter<'a, LetterRevelation>;
 
     fn into_iter(self) -> Self::IntoIter {
-        [&self.0, &self.1, &self.2, &self.3, &self.4].into_iter()
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feedback_round_trips() {
+        let revelation = WordRevelation::compute("crane", "caper");
+        assert_eq!(revelation.to_string(), "G Y Y B Y");
+        let parsed = WordRevelation::from_feedback("crane", "gyyby").unwrap();
+        assert_eq!(parsed.to_string(), revelation.to_string());
+        assert!(WordRevelation::from_feedback("crane", "G Y Y B").is_none());
+        assert!(WordRevelation::from_feedback("crane", "G Y Y B X").is_none());
     }
 }
@@ -0,0 +1,47 @@
+use crate::modules::{config::WORD_LENGTH, puzzle::Puzzle};
+
+use std::{env, path::PathBuf};
+
+/// Standard Wordle attempt limit.
+pub const MAX_ATTEMPTS: usize = 6;
+
+/// Options chosen when launching the game, kept across restarts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Settings {
+    /// Letters in every word.
+    pub word_length: usize,
+    /// Guesses must reuse every revealed hint.
+    pub hard_mode: bool,
+    /// Guesses allowed before the game is lost, `None` for unlimited zen play.
+    pub max_attempts: Option<usize>,
+    /// Allowed guesses, `WORDLE_WORDS` when not given on the command line.
+    pub words_path: Option<PathBuf>,
+    /// Possible answers, `WORDLE_ANSWERS` when not given on the command line.
+    pub answers_path: Option<PathBuf>,
+    /// How the answer is picked.
+    pub puzzle: Puzzle,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            word_length: WORD_LENGTH,
+            hard_mode: false,
+            max_attempts: Some(MAX_ATTEMPTS),
+            words_path: None,
+            answers_path: None,
+            puzzle: Puzzle::Random,
+        }
+    }
+}
+
+impl Settings {
+    /// Default settings reading the word list paths from the environment.
+    pub fn from_env() -> Settings {
+        Settings {
+            words_path: env::var_os("WORDLE_WORDS").map(PathBuf::from),
+            answers_path: env::var_os("WORDLE_ANSWERS").map(PathBuf::from),
+            ..Settings::default()
+        }
+    }
+}
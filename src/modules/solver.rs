@@ -1,43 +1,341 @@
-use crate::modules::revelation::WordRevelation;
+use crate::modules::{
+    pattern::{Pattern, PatternMatrix},
+    revelation::WordRevelation,
+};
 
 use super::{revelation::LetterRevelation, state::State};
 
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, mem,
+    rc::Rc,
+};
 
+/// How many times a letter can appear in the answer, as learned so far.
 #[derive(Clone, Copy, Debug)]
 pub struct LetterConstraint {
+    /// Fewest times it appears.
     pub min: usize,
+    /// Most times it appears, `None` when unknown.
     pub max: Option<usize>,
 }
 
-#[derive(Default)]
+/// A candidate guess and its score under the strategy that ranked it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuessScore {
+    /// The guess.
+    pub word: String,
+    /// Bits of information for entropy, words left at worst for minimax.
+    pub score: f64,
+}
+
+/// How guesses are ranked.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Strategy {
+    /// Maximise the expected information of the feedback.
+    #[default]
+    Entropy,
+    /// Minimise the largest group of possibilities left after the feedback.
+    Minimax,
+}
+
+/// A revealed hint that a hard mode guess fails to reuse.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HintViolation {
+    /// A green letter was not kept in its place.
+    Moved {
+        /// The green letter.
+        letter: char,
+        /// Where it must stay.
+        index: usize,
+    },
+    /// A revealed letter is used fewer times than the hints require.
+    Missing {
+        /// The revealed letter.
+        letter: char,
+        /// Times the guess must use it.
+        count: usize,
+    },
+}
+
+impl fmt::Display for HintViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HintViolation::Moved { letter, index } => write!(
+                f,
+                "{} letter must be {}",
+                ordinal(index + 1),
+                letter.to_uppercase()
+            ),
+            HintViolation::Missing { letter, count: 1 } => {
+                write!(f, "Guess must contain {}", letter.to_uppercase())
+            }
+            HintViolation::Missing { letter, count } => {
+                write!(f, "Guess must contain {count} {}s", letter.to_uppercase())
+            }
+        }
+    }
+}
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (1, 11) | (2, 12) | (3, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+/// Narrows down the possible answers from the revelations it is given and
+/// ranks the next guesses.
 pub struct Solver {
-    pub possibilities: Vec<String>,
-    pub revelations: Vec<LetterRevelation>,
-    pub constraints: HashMap<char, LetterConstraint>,
+    possibilities: Vec<String>,
+    guesses: Vec<String>,
+    revelations: Vec<LetterRevelation>,
+    constraints: HashMap<char, LetterConstraint>,
+    matrix: Option<Rc<PatternMatrix>>,
+    /// Only guesses reusing every hint are ranked.
+    hard_mode: bool,
 }
 
 impl Solver {
+    /// Solver guessing among the `possibilities` themselves.
     pub fn new(possibilities: Vec<String>) -> Solver {
+        Solver::with_guesses(possibilities.clone(), possibilities)
+    }
+
+    /// Solver narrowing down `possibilities` while ranking every word of
+    /// `guesses`.
+    pub fn with_guesses(possibilities: Vec<String>, guesses: Vec<String>) -> Solver {
         Solver {
             possibilities,
+            guesses,
             revelations: vec![],
             constraints: HashMap::new(),
+            matrix: None,
+            hard_mode: false,
+        }
+    }
+
+    /// Solver whose guesses and possibilities are the rows and columns of
+    /// `matrix`, so ranking only reads precomputed patterns.
+    pub fn with_matrix(matrix: Rc<PatternMatrix>) -> Solver {
+        Solver {
+            guesses: matrix.guesses().to_vec(),
+            possibilities: matrix.answers().to_vec(),
+            revelations: vec![],
+            constraints: HashMap::new(),
+            matrix: Some(matrix),
+            hard_mode: false,
+        }
+    }
+
+    /// Restricts the ranked guesses to the ones hard mode accepts.
+    pub fn set_hard_mode(&mut self, hard_mode: bool) {
+        self.hard_mode = hard_mode;
+    }
+
+    /// Lets an existing solver rank through `matrix`; words it does not cover
+    /// are still scored directly.
+    pub fn attach_matrix(&mut self, matrix: Rc<PatternMatrix>) {
+        self.matrix = Some(matrix);
+    }
+
+    fn calculate_probability_of_round(&self, number_of_possible_word_after_guess: usize) -> f64 {
+        (number_of_possible_word_after_guess as f64) / (self.number_of_possibilities() as f64)
+    }
+
+    /// Columns of the matrix holding the current possibilities, if every one
+    /// of them is covered by it.
+    fn answer_indices(&self) -> Option<Vec<usize>> {
+        let matrix = self.matrix.as_ref()?;
+        self.possibilities
+            .iter()
+            .map(|word| matrix.answer_index(word))
+            .collect()
+    }
+
+    /// Groups the remaining possibilities by the feedback `guess` would get
+    /// against each of them, returning the size of every group, or `None` if
+    /// `guess` can't be scored against them. `buckets` is scratch space
+    /// handed back zeroed, so ranking many guesses allocates it once even for
+    /// the 6561 patterns of eight letter words.
+    fn simulate_revelation(
+        &self,
+        guess: &str,
+        answers: Option<&[usize]>,
+        buckets: &mut Vec<usize>,
+    ) -> Option<Vec<usize>> {
+        let row = self
+            .matrix
+            .as_ref()
+            .and_then(|matrix| Some(matrix.row(matrix.guess_index(guess)?)));
+        let patterns: Vec<Pattern> = match (row, answers) {
+            (Some(row), Some(answers)) => answers.iter().map(|&answer| row[answer]).collect(),
+            _ => self
+                .possibilities
+                .iter()
+                .map(|answer| Pattern::compute(guess, answer))
+                .collect::<Option<_>>()?,
+        };
+        let count = Pattern::count(guess.chars().count());
+        if buckets.len() < count {
+            buckets.resize(count, 0);
+        }
+        for pattern in &patterns {
+            buckets[pattern.index()] += 1;
+        }
+        let mut sizes: Vec<usize> = patterns
+            .iter()
+            .map(|pattern| mem::take(&mut buckets[pattern.index()]))
+            .filter(|&size| size > 0)
+            .collect();
+        // summing in a fixed order keeps equal partitions at equal scores
+        sizes.sort_unstable();
+        Some(sizes)
+    }
+
+    fn entropy_of(&self, sizes: &[usize]) -> f64 {
+        sizes
+            .iter()
+            .map(|&size| {
+                let p = self.calculate_probability_of_round(size);
+                -p * p.log2()
+            })
+            .sum()
+    }
+
+    /// Expected information, in bits, gained by playing `guess`. `None`
+    /// when `guess` isn't as long as the possibilities.
+    pub fn entropy(&self, guess: &str) -> Option<f64> {
+        let answers = self.answer_indices();
+        let sizes = self.simulate_revelation(guess, answers.as_deref(), &mut vec![])?;
+        Some(self.entropy_of(&sizes))
+    }
+
+    /// Number of possibilities left in the worst case after playing `guess`.
+    /// `None` when `guess` isn't as long as the possibilities.
+    pub fn worst_case(&self, guess: &str) -> Option<usize> {
+        let answers = self.answer_indices();
+        let sizes = self.simulate_revelation(guess, answers.as_deref(), &mut vec![])?;
+        Some(sizes.last().copied().unwrap_or(0))
+    }
+
+    /// Ranks every allowed guess by entropy and returns the `n` best. Ties go
+    /// to words that can still be the answer.
+    pub fn best_guesses(&self, n: usize) -> Vec<GuessScore> {
+        self.best_guesses_by(Strategy::Entropy, n)
+    }
+
+    /// Ranks every allowed guess with `strategy` and returns the `n` best,
+    /// leaving out the ones breaking hard mode when it is set and the ones
+    /// that can't be scored.
+    /// Entropy scores are in bits (higher is better), minimax scores are the
+    /// worst-case number of remaining words (lower is better).
+    pub fn best_guesses_by(&self, strategy: Strategy, n: usize) -> Vec<GuessScore> {
+        if self.possibilities.is_empty() {
+            return vec![];
+        }
+        let answers = self.answer_indices();
+        let candidates: HashSet<&str> = self.possibilities.iter().map(|w| w.as_str()).collect();
+        let mut buckets = vec![];
+        let mut scores: Vec<GuessScore> = Vec::with_capacity(self.guesses.len());
+        for word in &self.guesses {
+            if self.hard_mode && self.hard_mode_violation(word).is_some() {
+                continue;
+            }
+            let Some(sizes) = self.simulate_revelation(word, answers.as_deref(), &mut buckets)
+            else {
+                continue;
+            };
+            scores.push(GuessScore {
+                word: word.clone(),
+                score: match strategy {
+                    Strategy::Entropy => self.entropy_of(&sizes),
+                    Strategy::Minimax => sizes.last().copied().unwrap_or(0) as f64,
+                },
+            });
         }
+        scores.sort_by(|a, b| {
+            let by_score = match strategy {
+                Strategy::Entropy => b.score.total_cmp(&a.score),
+                Strategy::Minimax => a.score.total_cmp(&b.score),
+            };
+            by_score
+                .then_with(|| {
+                    candidates
+                        .contains(b.word.as_str())
+                        .cmp(&candidates.contains(a.word.as_str()))
+                })
+                .then_with(|| a.word.cmp(&b.word))
+        });
+        scores.truncate(n);
+        scores
     }
 
-    fn calculate_probability_of_round(&self, number_of_possible_word_before_guess: usize) -> f64 {
-        (self.number_of_possibilities() as f64) / (number_of_possible_word_before_guess as f64)
+    /// Answers still consistent with every revelation.
+    pub fn possibilities(&self) -> &[String] {
+        &self.possibilities
     }
 
-    fn simulate_revelation(&mut self) {
-        let old_possibilities = &self.possibilities;
+    /// Words the solver may suggest.
+    pub fn guesses(&self) -> &[String] {
+        &self.guesses
     }
 
+    /// Every letter revelation given so far.
+    pub fn revelations(&self) -> &[LetterRevelation] {
+        &self.revelations
+    }
+
+    /// What the revelations tell about how often each letter appears.
+    pub fn constraints(&self) -> &HashMap<char, LetterConstraint> {
+        &self.constraints
+    }
+
+    /// Number of answers still possible.
     pub fn number_of_possibilities(&self) -> usize {
         self.possibilities.len()
     }
 
+    /// First hint `guess` ignores under hard mode rules: green letters stay
+    /// in place and every revealed letter is used at least as often as the
+    /// hints showed.
+    pub fn hard_mode_violation(&self, guess: &str) -> Option<HintViolation> {
+        let letters: Vec<char> = guess.chars().collect();
+        let mut greens: Vec<&LetterRevelation> = self
+            .revelations
+            .iter()
+            .filter(|rev| rev.state == State::Correct)
+            .collect();
+        greens.sort_by_key(|rev| rev.index);
+        if let Some(rev) = greens
+            .into_iter()
+            .find(|rev| letters.get(rev.index) != Some(&rev.letter))
+        {
+            return Some(HintViolation::Moved {
+                letter: rev.letter,
+                index: rev.index,
+            });
+        }
+
+        let mut required: Vec<(char, usize)> = self
+            .constraints
+            .iter()
+            .filter(|(_, constraint)| constraint.min > 0)
+            .map(|(letter, constraint)| (*letter, constraint.min))
+            .collect();
+        required.sort_unstable();
+        required
+            .into_iter()
+            .find(|(letter, min)| letters.iter().filter(|c| *c == letter).count() < *min)
+            .map(|(letter, count)| HintViolation::Missing { letter, count })
+    }
+
+    /// Learns the hints of one guess and drops the possibilities they rule
+    /// out.
     pub fn add_revelations(&mut self, revelations: &WordRevelation) {
         let mut current_guess_min: HashMap<char, usize> = HashMap::new();
 
@@ -81,22 +379,23 @@ impl Solver {
         self.possibilities = self.filtering_possibilities();
     }
 
-    fn filter_word(&self, word: &str) -> bool {
-        let bytes = word.as_bytes();
+    /// Whether `word` agrees with every revelation added so far.
+    pub fn filter_word(&self, word: &str) -> bool {
+        let letters: Vec<char> = word.chars().collect();
 
         let positional_ok = self.revelations.iter().all(|rev| match rev.state {
-            State::Correct => bytes[rev.index] == rev.letter as u8,
-            State::Change => bytes[rev.index] != rev.letter as u8,
+            State::Correct => letters.get(rev.index) == Some(&rev.letter),
+            State::Change => letters.get(rev.index) != Some(&rev.letter),
             State::Wrong => {
                 let constraint = self.constraints.get(&rev.letter);
                 if let Some(c) = constraint {
                     if c.max == Some(c.min) && c.min > 0 {
                         true
                     } else {
-                        bytes[rev.index] != rev.letter as u8
+                        letters.get(rev.index) != Some(&rev.letter)
                     }
                 } else {
-                    bytes[rev.index] != rev.letter as u8
+                    letters.get(rev.index) != Some(&rev.letter)
                 }
             }
         });
@@ -112,7 +411,7 @@ impl Solver {
 
         self.constraints.iter().all(|(letter, constraint)| {
             let actual = *counts.get(letter).unwrap_or(&0);
-            actual >= constraint.min && constraint.max.map_or(true, |max| actual <= max)
+            actual >= constraint.min && constraint.max.is_none_or(|max| actual <= max)
         })
     }
 
@@ -260,4 +559,100 @@ mod tests {
         dbg!(&solver.constraints);
         assert!(solver.filter_word("spide"));
     }
+
+    #[test]
+    fn best_guess_splits_possibilities() {
+        let words: Vec<String> = ["cigar", "rebut", "sissy", "humph", "awake"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        let solver = Solver::new(words);
+        let best = solver.best_guesses(5);
+        assert_eq!(best.len(), 5);
+        assert!(best.windows(2).all(|w| w[0].score >= w[1].score));
+        assert!((solver.entropy("cigar").unwrap() - 5f64.log2()).abs() < 1e-9);
+        assert_eq!(solver.entropy("abcdefghi"), None);
+
+        let mut solver = Solver::new(vec!["cigar".to_string()]);
+        solver.guesses.push("sissy".to_string());
+        assert_eq!(solver.best_guesses(1)[0].word, "cigar");
+    }
+
+    #[test]
+    fn buckets_fit_non_ascii_words() {
+        let words: Vec<String> = ["картошка", "карандаш", "кукуруза", "колбаска"]
+            .map(String::from)
+            .into();
+        let solver = Solver::new(words);
+        let mut buckets = vec![];
+        let sizes = solver
+            .simulate_revelation("картошка", None, &mut buckets)
+            .unwrap();
+        assert_eq!(sizes.iter().sum::<usize>(), 4);
+        assert_eq!(buckets.len(), Pattern::count(8));
+        assert_eq!(solver.best_guesses(4).len(), 4);
+        assert_eq!(solver.entropy("картошка"), Some(2.0));
+    }
+
+    #[test]
+    fn minimax_bounds_worst_case() {
+        let words: Vec<String> = ["baker", "bakes", "baked", "cakes", "fakes"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        let solver = Solver::new(words);
+        assert_eq!(solver.worst_case("baker"), Some(2));
+        let best = solver.best_guesses_by(Strategy::Minimax, 5);
+        assert!(best.windows(2).all(|w| w[0].score <= w[1].score));
+        assert_eq!(
+            Some(best[0].score as usize),
+            solver.worst_case(&best[0].word)
+        );
+    }
+
+    #[test]
+    fn matrix_ranking_matches_direct_ranking() {
+        let words: Vec<String> = ["baker", "bakes", "baked", "cakes", "fakes", "crane"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        let direct = Solver::new(words.clone());
+        let matrix = Rc::new(PatternMatrix::new(words.clone(), words).unwrap());
+        let cached = Solver::with_matrix(matrix);
+        for strategy in [Strategy::Entropy, Strategy::Minimax] {
+            assert_eq!(
+                direct.best_guesses_by(strategy, 6),
+                cached.best_guesses_by(strategy, 6)
+            );
+        }
+    }
+
+    #[test]
+    fn hard_mode_reports_ignored_hints() {
+        let mut solver = Solver::new(vec![]);
+        solver.add_revelations(&WordRevelation::compute("crane", "caper"));
+        assert_eq!(
+            solver.hard_mode_violation("slate"),
+            Some(HintViolation::Moved {
+                letter: 'c',
+                index: 0
+            })
+        );
+        assert_eq!(
+            solver.hard_mode_violation("chair"),
+            Some(HintViolation::Missing {
+                letter: 'e',
+                count: 1
+            })
+        );
+        assert_eq!(solver.hard_mode_violation("cater"), None);
+        assert_eq!(
+            HintViolation::Moved {
+                letter: 'c',
+                index: 0
+            }
+            .to_string(),
+            "1st letter must be C"
+        );
+    }
 }
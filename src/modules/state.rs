@@ -1,6 +1,10 @@
-#[derive(Debug, Clone, PartialEq)]
+/// Feedback for one letter of a guess.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum State {
+    /// Right letter in the right place, green.
     Correct,
+    /// Letter not in the answer, or not that many times, gray.
     Wrong,
+    /// Letter in the answer but elsewhere, yellow.
     Change,
 }
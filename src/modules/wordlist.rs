@@ -0,0 +1,259 @@
+//! Reading and checking word list files.
+
+use crate::modules::config::{MAX_WORD_LENGTH, MIN_WORD_LENGTH};
+
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Why a word list can't be used.
+#[derive(Debug)]
+pub enum WordListError {
+    /// The file can't be read.
+    Io {
+        /// The list file.
+        path: PathBuf,
+        /// What reading it failed with.
+        source: io::Error,
+    },
+    /// A line holds something other than a single word of letters.
+    BadLine {
+        /// The list file.
+        path: PathBuf,
+        /// Line number, from 1.
+        line: usize,
+        /// The whole line.
+        content: String,
+    },
+    /// A word doesn't have the expected number of letters.
+    WrongLength {
+        /// The list file.
+        path: PathBuf,
+        /// Line number, from 1.
+        line: usize,
+        /// The word, lowercased.
+        word: String,
+        /// Letters every word must have.
+        expected: usize,
+    },
+    /// The file has no words.
+    Empty {
+        /// The list file.
+        path: PathBuf,
+    },
+    /// No list was given and none is built in for this length.
+    NoBuiltIn {
+        /// Length that was asked for.
+        word_length: usize,
+    },
+    /// A word passed in memory isn't as long as the others, or not from
+    /// `MIN_WORD_LENGTH` to `MAX_WORD_LENGTH` letters long.
+    InvalidWord {
+        /// The word.
+        word: String,
+    },
+    /// There is no answer to pick from.
+    NoAnswers,
+}
+
+impl fmt::Display for WordListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordListError::Io { path, source } => {
+                write!(f, "can't read {}: {source}", path.display())
+            }
+            WordListError::BadLine {
+                path,
+                line,
+                content,
+            } => write!(
+                f,
+                "{}:{line}: `{content}` is not a single word of letters",
+                path.display()
+            ),
+            WordListError::WrongLength {
+                path,
+                line,
+                word,
+                expected,
+            } => write!(
+                f,
+                "{}:{line}: `{word}` is not {expected} letters long",
+                path.display()
+            ),
+            WordListError::Empty { path } => write!(f, "{} has no words", path.display()),
+            WordListError::NoBuiltIn { word_length } => write!(
+                f,
+                "there is no built-in list of {word_length} letter words, pass one with --words"
+            ),
+            WordListError::InvalidWord { word } => write!(
+                f,
+                "`{word}` is not a word of {MIN_WORD_LENGTH} to {MAX_WORD_LENGTH} letters as long as the others"
+            ),
+            WordListError::NoAnswers => write!(f, "there are no answers to pick from"),
+        }
+    }
+}
+
+impl Error for WordListError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WordListError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Reads a word list with one word per line.
+pub fn load(path: &Path, word_length: usize) -> Result<Vec<String>, WordListError> {
+    let contents = fs::read_to_string(path).map_err(|source| WordListError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    parse(path, &contents, word_length)
+}
+
+/// Parses one word per line, lowercased. Blank lines and lines starting with
+/// `#` are skipped, duplicates are dropped keeping the first occurrence.
+pub fn parse(
+    path: &Path,
+    contents: &str,
+    word_length: usize,
+) -> Result<Vec<String>, WordListError> {
+    let mut seen = HashSet::new();
+    let mut words = vec![];
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+        let word = line.trim();
+        if word.is_empty() || word.starts_with('#') {
+            continue;
+        }
+        // decomposed accents are combining marks, not letters, so lists
+        // have to use precomposed characters
+        if !word.chars().all(char::is_alphabetic) {
+            return Err(WordListError::BadLine {
+                path: path.to_path_buf(),
+                line: line_number,
+                content: line.to_string(),
+            });
+        }
+        // lowercasing can add characters, `İ` becomes `i` and a combining dot
+        let word = word.to_lowercase();
+        if word.chars().count() != word_length {
+            return Err(WordListError::WrongLength {
+                path: path.to_path_buf(),
+                line: line_number,
+                word,
+                expected: word_length,
+            });
+        }
+        if seen.insert(word.clone()) {
+            words.push(word);
+        }
+    }
+    if words.is_empty() {
+        return Err(WordListError::Empty {
+            path: path.to_path_buf(),
+        });
+    }
+    Ok(words)
+}
+
+/// Letters in each of `words`, checking they all have as many, from
+/// `MIN_WORD_LENGTH` to `MAX_WORD_LENGTH`. `None` when there are no words.
+pub fn common_length<'a>(
+    words: impl IntoIterator<Item = &'a String>,
+) -> Result<Option<usize>, WordListError> {
+    let mut length = None;
+    for word in words {
+        let letters = word.chars().count();
+        if !(MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&letters)
+            || length.is_some_and(|length| length != letters)
+        {
+            return Err(WordListError::InvalidWord { word: word.clone() });
+        }
+        length = Some(letters);
+    }
+    Ok(length)
+}
+
+/// FNV-1a hash of `words`, one per line, identifying a word list.
+pub fn hash<'a>(words: impl IntoIterator<Item = &'a String>) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for word in words {
+        for byte in word.bytes().chain([b'\n']) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::modules::config::Config;
+
+    #[test]
+    fn parse_reports_line_numbers() {
+        let path = Path::new("list.txt");
+        assert_eq!(
+            parse(path, "# team words\nCrane\n\nslate\ncrane\n", 5).unwrap(),
+            vec!["crane", "slate"]
+        );
+        assert_eq!(
+            parse(path, "Élève\nstraße\n", 5).unwrap_err().to_string(),
+            "list.txt:2: `straße` is not 5 letters long"
+        );
+        assert_eq!(parse(path, "Élève\n", 5).unwrap(), vec!["élève"]);
+        assert!(matches!(
+            parse(path, "e\u{301}leve\n", 5),
+            Err(WordListError::BadLine { line: 1, .. })
+        ));
+        assert!(matches!(
+            parse(path, "crane\nno way\n", 5),
+            Err(WordListError::BadLine { line: 2, .. })
+        ));
+        assert!(matches!(
+            parse(path, "crane\nslates\n", 5),
+            Err(WordListError::WrongLength { line: 2, .. })
+        ));
+        assert!(matches!(
+            parse(path, "crane\nİzmir\n", 5),
+            Err(WordListError::WrongLength { line: 2, .. })
+        ));
+        assert!(matches!(
+            parse(path, "İİİİİİİİ\n", 8),
+            Err(WordListError::WrongLength { line: 1, .. })
+        ));
+        assert!(matches!(
+            parse(path, "# nothing\n", 5),
+            Err(WordListError::Empty { .. })
+        ));
+        assert!(matches!(
+            load(Path::new("/nonexistent/words.txt"), 5),
+            Err(WordListError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn in_memory_lists_are_checked() {
+        let words = |list: &[&str]| -> Vec<String> { list.iter().map(|w| w.to_string()).collect() };
+        assert_eq!(common_length(&words(&["crane", "élève"])).unwrap(), Some(5));
+        assert_eq!(common_length(&words(&[])).unwrap(), None);
+        assert!(matches!(
+            common_length(&words(&["crane", "slates"])),
+            Err(WordListError::InvalidWord { word }) if word == "slates"
+        ));
+        assert!(common_length(&words(&["abcdefghi"])).is_err());
+        assert!(matches!(
+            Config::new(None, words(&["crane"]), vec![]),
+            Err(WordListError::NoAnswers)
+        ));
+        assert!(Config::new(None, words(&["crane"]), words(&["cranes"])).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use std::time::{Duration, Instant};
+
+/// How often the game loop wakes up without a key press.
+pub const TICK_RATE: Duration = Duration::from_millis(50);
+
+const FLIP_DURATION: Duration = Duration::from_millis(250);
+const SHAKE_STEP: Duration = Duration::from_millis(50);
+const SHAKE_OFFSETS: [i16; 6] = [-2, 2, -2, 2, -1, 1];
+
+#[derive(Clone, Copy, Debug)]
+pub enum Animation {
+    /// The tiles of the last guess flip one after the other.
+    Reveal { started: Instant, tiles: usize },
+    /// The row being typed shakes after an invalid guess.
+    Shake { started: Instant },
+}
+
+impl Animation {
+    pub fn reveal(tiles: usize) -> Animation {
+        Animation::Reveal {
+            started: Instant::now(),
+            tiles,
+        }
+    }
+
+    pub fn shake() -> Animation {
+        Animation::Shake {
+            started: Instant::now(),
+        }
+    }
+
+    pub fn is_done(&self, now: Instant) -> bool {
+        match self {
+            Animation::Reveal { started, tiles } => {
+                now.duration_since(*started) >= FLIP_DURATION * *tiles as u32
+            }
+            Animation::Shake { started } => {
+                now.duration_since(*started) >= SHAKE_STEP * SHAKE_OFFSETS.len() as u32
+            }
+        }
+    }
+
+    /// Tiles of the last guess flipped so far, `None` when not revealing.
+    pub fn flipped(&self, now: Instant) -> Option<usize> {
+        match self {
+            Animation::Reveal { started, tiles } => {
+                let elapsed = now.duration_since(*started).as_millis();
+                Some(((elapsed / FLIP_DURATION.as_millis()) as usize).min(*tiles))
+            }
+            Animation::Shake { .. } => None,
+        }
+    }
+
+    /// Columns the row being typed is pushed sideways by.
+    pub fn shake_offset(&self, now: Instant) -> i16 {
+        match self {
+            Animation::Shake { started } => {
+                let step = now.duration_since(*started).as_millis() / SHAKE_STEP.as_millis();
+                SHAKE_OFFSETS.get(step as usize).copied().unwrap_or(0)
+            }
+            Animation::Reveal { .. } => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reveal_flips_one_tile_per_step() {
+        let started = Instant::now();
+        let reveal = Animation::Reveal { started, tiles: 5 };
+        assert_eq!(reveal.flipped(started), Some(0));
+        assert_eq!(reveal.flipped(started + FLIP_DURATION * 2), Some(2));
+        assert_eq!(reveal.flipped(started + FLIP_DURATION * 9), Some(5));
+        assert!(!reveal.is_done(started + FLIP_DURATION * 4));
+        assert!(reveal.is_done(started + FLIP_DURATION * 5));
+        assert_eq!(reveal.shake_offset(started), 0);
+    }
+
+    #[test]
+    fn shake_settles_back_in_place() {
+        let started = Instant::now();
+        let shake = Animation::Shake { started };
+        assert_eq!(shake.shake_offset(started), SHAKE_OFFSETS[0]);
+        assert_eq!(
+            shake.shake_offset(started + SHAKE_STEP * 3),
+            SHAKE_OFFSETS[3]
+        );
+        let end = started + SHAKE_STEP * SHAKE_OFFSETS.len() as u32;
+        assert_eq!(shake.shake_offset(end), 0);
+        assert!(shake.is_done(end));
+        assert_eq!(shake.flipped(started), None);
+    }
+}
@@ -0,0 +1,250 @@
+//! Plays a solver strategy against every answer to measure it.
+
+use rand::{SeedableRng, rngs::StdRng, seq::IndexedRandom};
+use std::{
+    fmt,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+use wordle::{Config, PatternMatrix, Solver, State, Strategy};
+
+const WORST_WORDS: usize = 10;
+
+/// What `run` measures.
+#[derive(Default)]
+pub struct BenchOptions {
+    /// Strategy picking every guess.
+    pub strategy: Strategy,
+    /// Play only this many randomly chosen answers instead of all of them.
+    pub sample: Option<usize>,
+    /// Draws the sample from a generator seeded with this value.
+    pub seed: Option<u64>,
+}
+
+/// Guesses the solver needed for each answer it played.
+pub struct BenchReport {
+    /// Strategy that was measured.
+    pub strategy: Strategy,
+    /// Guesses beyond this count as failures, `None` never fails.
+    pub max_attempts: Option<usize>,
+    /// Number of guesses each answer took, failures included.
+    pub results: Vec<(String, usize)>,
+    /// Time the whole run took.
+    pub elapsed: Duration,
+}
+
+impl BenchReport {
+    /// Number of answers played.
+    pub fn games(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Answers that took more guesses than allowed.
+    pub fn failures(&self) -> Vec<&str> {
+        self.results
+            .iter()
+            .filter(|(_, guesses)| self.max_attempts.is_some_and(|max| *guesses > max))
+            .map(|(word, _)| word.as_str())
+            .collect()
+    }
+
+    /// Mean number of guesses per answer, failures included.
+    pub fn average_guesses(&self) -> f64 {
+        let total: usize = self.results.iter().map(|(_, guesses)| guesses).sum();
+        total as f64 / self.games().max(1) as f64
+    }
+
+    /// `histogram()[n - 1]` is the number of answers found in `n` guesses.
+    /// Without a limit it runs up to the longest game.
+    pub fn histogram(&self) -> Vec<usize> {
+        let longest = self.results.iter().map(|(_, guesses)| *guesses).max();
+        let mut histogram = vec![0; self.max_attempts.or(longest).unwrap_or(0)];
+        for (_, guesses) in &self.results {
+            if let Some(count) = histogram.get_mut(guesses - 1) {
+                *count += 1;
+            }
+        }
+        histogram
+    }
+
+    /// The `n` answers that took the most guesses, longest first.
+    pub fn worst_words(&self, n: usize) -> Vec<&(String, usize)> {
+        let mut worst: Vec<&(String, usize)> = self.results.iter().collect();
+        worst.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        worst.truncate(n);
+        worst
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let games = self.games();
+        let failures = self.failures();
+        writeln!(
+            f,
+            "{:?} strategy over {} words in {:.1?}",
+            self.strategy, games, self.elapsed
+        )?;
+        writeln!(f, "average guesses: {:.3}", self.average_guesses())?;
+        let histogram = self.histogram();
+        let widest = histogram.iter().copied().max().unwrap_or(0).max(1);
+        for (i, count) in histogram.iter().enumerate() {
+            let bar = "#".repeat(count * 40 / widest);
+            writeln!(f, "{:>3}: {:>6} {}", i + 1, count, bar)?;
+        }
+        if let Some(max_attempts) = self.max_attempts {
+            writeln!(
+                f,
+                "failures (> {} guesses): {}",
+                max_attempts,
+                failures.len()
+            )?;
+            if !failures.is_empty() {
+                writeln!(f, "  {}", failures.join(" "))?;
+            }
+        }
+        writeln!(f, "worst words:")?;
+        for (word, guesses) in self.worst_words(WORST_WORDS) {
+            writeln!(f, "  {word} {guesses}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Best first guess of `strategy` over the words of `matrix`.
+pub fn opener(matrix: &Rc<PatternMatrix>, strategy: Strategy) -> Option<String> {
+    Solver::with_matrix(matrix.clone())
+        .best_guesses_by(strategy, 1)
+        .pop()
+        .map(|score| score.word)
+}
+
+/// Guesses the solver plays until it finds the answer of `config`, starting
+/// with `opener`. Games keep going past any attempt limit, and stop short of
+/// the answer only when no word is left to try.
+pub fn solve(
+    config: &Config,
+    matrix: &Rc<PatternMatrix>,
+    strategy: Strategy,
+    opener: Option<String>,
+) -> Vec<String> {
+    let mut solver = Solver::with_matrix(matrix.clone());
+    let mut guesses = vec![];
+    let mut guess = opener;
+    while let Some(word) = guess {
+        let revelation = config.check(&word);
+        guesses.push(word);
+        if (&revelation).into_iter().all(|r| r.state == State::Correct) {
+            break;
+        }
+        solver.add_revelations(&revelation);
+        guess = solver
+            .best_guesses_by(strategy, 1)
+            .pop()
+            .map(|score| score.word);
+    }
+    guesses
+}
+
+/// Plays the solver against every answer in `config` (or a sample of them),
+/// with `Config::check` giving the feedback.
+pub fn run(
+    mut config: Config,
+    matrix: Rc<PatternMatrix>,
+    options: &BenchOptions,
+    max_attempts: Option<usize>,
+) -> BenchReport {
+    let started = Instant::now();
+    let answers: Vec<String> = match options.sample {
+        Some(n) => matrix
+            .answers()
+            .choose_multiple(
+                &mut options
+                    .seed
+                    .map_or_else(StdRng::from_os_rng, StdRng::seed_from_u64),
+                n,
+            )
+            .cloned()
+            .collect(),
+        None => matrix.answers().to_vec(),
+    };
+    // every game opens with the same position, rank it only once
+    let opener = opener(&matrix, options.strategy);
+
+    let mut results = Vec::with_capacity(answers.len());
+    for answer in answers {
+        config.choose_word(&answer);
+        let attempts = solve(&config, &matrix, options.strategy, opener.clone()).len();
+        results.push((answer, attempts));
+    }
+
+    BenchReport {
+        strategy: options.strategy,
+        max_attempts,
+        results,
+        elapsed: started.elapsed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_counts_guesses_and_failures() {
+        let report = BenchReport {
+            strategy: Strategy::Entropy,
+            max_attempts: Some(3),
+            results: [("crane", 2), ("slate", 4), ("pious", 1), ("eerie", 4)]
+                .map(|(word, guesses)| (String::from(word), guesses))
+                .into(),
+            elapsed: Duration::ZERO,
+        };
+        assert_eq!(report.histogram(), vec![1, 1, 0]);
+        assert_eq!(report.failures(), vec!["slate", "eerie"]);
+        assert_eq!(report.average_guesses(), 2.75);
+        let worst: Vec<&str> = report
+            .worst_words(3)
+            .iter()
+            .map(|(word, _)| word.as_str())
+            .collect();
+        assert_eq!(worst, vec!["eerie", "slate", "crane"]);
+        assert_eq!(
+            report.to_string(),
+            "\
+Entropy strategy over 4 words in 0.0ns
+average guesses: 2.750
+  1:      1 ########################################
+  2:      1 ########################################
+  3:      0 \n\
+failures (> 3 guesses): 2
+  slate eerie
+worst words:
+  eerie 4
+  slate 4
+  crane 2
+  pious 1
+"
+        );
+    }
+
+    #[test]
+    fn seeded_samples_repeat() {
+        let words: Vec<String> = ["crane", "slate", "trace", "crate", "plate", "grate"]
+            .map(String::from)
+            .into();
+        let config = Config::new(None, words.clone(), words).unwrap();
+        let matrix = Rc::new(PatternMatrix::load_or_build(&config, None));
+        let options = BenchOptions {
+            sample: Some(4),
+            seed: Some(3),
+            ..BenchOptions::default()
+        };
+        let first = run(config.clone(), matrix.clone(), &options, Some(2));
+        let second = run(config, matrix, &options, Some(2));
+        assert_eq!(first.results, second.results);
+        assert_eq!(first.games(), 4);
+        let found: usize = first.histogram().iter().sum();
+        assert_eq!(found + first.failures().len(), 4);
+    }
+}
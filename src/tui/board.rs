@@ -0,0 +1,185 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Style, Stylize},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use wordle::{State, WordRevelation};
+
+const TILE_WIDTH: u16 = 5;
+const TILE_HEIGHT: u16 = 3;
+
+/// Grid of letter tiles: one row per allowed attempt, past guesses colored,
+/// the guess being typed in the next row and empty tiles after it.
+pub struct Board<'a> {
+    pub revelations: &'a [WordRevelation],
+    pub guess: &'a str,
+    pub word_length: usize,
+    /// Rows to draw, `None` grows with the guesses in zen mode.
+    pub max_attempts: Option<usize>,
+    pub finished: bool,
+    /// Tiles of the last guess flipped so far while it is being revealed.
+    pub flipped: Option<usize>,
+    /// Sideways offset of the row being typed while it shakes.
+    pub shake: i16,
+}
+
+enum Tile {
+    Revealed(char, State),
+    Typed(char),
+    Empty,
+}
+
+impl Board<'_> {
+    fn rows(&self) -> Vec<Vec<Tile>> {
+        let mut rows: Vec<Vec<Tile>> = self
+            .revelations
+            .iter()
+            .map(|revelation| {
+                revelation
+                    .into_iter()
+                    .map(|r| Tile::Revealed(r.letter, r.state.clone()))
+                    .collect()
+            })
+            .collect();
+        if let (Some(flipped), Some(last)) = (self.flipped, rows.last_mut()) {
+            for tile in last.iter_mut().skip(flipped) {
+                if let Tile::Revealed(letter, _) = tile {
+                    *tile = Tile::Typed(*letter);
+                }
+            }
+        }
+        if !self.finished {
+            let mut typed: Vec<Tile> = self.guess.chars().map(Tile::Typed).collect();
+            typed.resize_with(self.word_length, || Tile::Empty);
+            rows.push(typed);
+        }
+        let total = self.max_attempts.unwrap_or(rows.len()).max(rows.len());
+        rows.resize_with(total, || {
+            (0..self.word_length).map(|_| Tile::Empty).collect()
+        });
+        rows
+    }
+
+    fn tile_style(tile: &Tile) -> Style {
+        match tile {
+            Tile::Revealed(_, State::Correct) => Style::new().black().on_green(),
+            Tile::Revealed(_, State::Change) => Style::new().black().on_yellow(),
+            Tile::Revealed(_, State::Wrong) => Style::new().white().on_dark_gray(),
+            Tile::Typed(_) => Style::new().white(),
+            Tile::Empty => Style::new().dark_gray(),
+        }
+    }
+}
+
+impl Widget for &Board<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let rows = self.rows();
+        let width = self.word_length as u16 * TILE_WIDTH;
+        // fall back to one line per row when bordered tiles don't fit
+        let tile_height = if rows.len() as u16 * TILE_HEIGHT <= area.height {
+            TILE_HEIGHT
+        } else {
+            1
+        };
+        let visible = (area.height / tile_height) as usize;
+        let left = area.x + area.width.saturating_sub(width) / 2;
+        // in a long zen game keep the latest rows on screen
+        let skipped = rows.len().saturating_sub(visible);
+        let typing_row = (!self.finished).then_some(self.revelations.len());
+        for (y, row) in rows.iter().enumerate().skip(skipped) {
+            let row_left = if Some(y) == typing_row {
+                left.saturating_add_signed(self.shake).max(area.x)
+            } else {
+                left
+            };
+            let y = y - skipped;
+            for (x, tile) in row.iter().enumerate() {
+                let tile_area = Rect::new(
+                    row_left + x as u16 * TILE_WIDTH,
+                    area.y + y as u16 * tile_height,
+                    TILE_WIDTH,
+                    tile_height,
+                )
+                .intersection(area);
+                let letter = match tile {
+                    Tile::Revealed(letter, _) | Tile::Typed(letter) => {
+                        letter.to_uppercase().to_string()
+                    }
+                    Tile::Empty if tile_height == TILE_HEIGHT => String::new(),
+                    Tile::Empty => String::from("·"),
+                };
+                let style = Board::tile_style(tile);
+                let paragraph = Paragraph::new(letter.bold())
+                    .alignment(Alignment::Center)
+                    .style(style);
+                if tile_height == TILE_HEIGHT {
+                    paragraph
+                        .block(Block::bordered().border_style(style))
+                        .render(tile_area, buf);
+                } else {
+                    paragraph.render(tile_area, buf);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board<'a>(revelations: &'a [WordRevelation], max_attempts: Option<usize>) -> Board<'a> {
+        Board {
+            revelations,
+            guess: "cr",
+            word_length: 5,
+            max_attempts,
+            finished: false,
+            flipped: None,
+            shake: 0,
+        }
+    }
+
+    fn empty_tiles(rows: &[Vec<Tile>]) -> usize {
+        rows.iter()
+            .flatten()
+            .filter(|tile| matches!(tile, Tile::Empty))
+            .count()
+    }
+
+    #[test]
+    fn rows_follow_the_attempt_limit() {
+        let revelations = [
+            WordRevelation::compute("speed", "abide"),
+            WordRevelation::compute("abide", "abide"),
+        ];
+        let rows = board(&revelations, Some(6)).rows();
+        assert_eq!(rows.len(), 6);
+        assert!(matches!(rows[2][1], Tile::Typed('r')));
+        assert_eq!(empty_tiles(&rows), 3 + 3 * 5);
+
+        // zen mode only adds the row being typed
+        let rows = board(&revelations, None).rows();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(empty_tiles(&rows), 3);
+        let finished = Board {
+            finished: true,
+            ..board(&revelations, None)
+        };
+        assert_eq!(finished.rows().len(), 2);
+    }
+
+    #[test]
+    fn unflipped_tiles_hide_their_color() {
+        let revelations = [WordRevelation::compute("abide", "abide")];
+        let revealing = Board {
+            flipped: Some(2),
+            ..board(&revelations, Some(6))
+        };
+        let rows = revealing.rows();
+        assert!(matches!(rows[0][1], Tile::Revealed('b', State::Correct)));
+        assert!(matches!(rows[0][2], Tile::Typed('i')));
+    }
+}
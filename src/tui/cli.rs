@@ -0,0 +1,296 @@
+use crate::tui::{bench::BenchOptions, commands::SolveOptions, host::HostOptions};
+use wordle::{MAX_WORD_LENGTH, MIN_WORD_LENGTH, Puzzle, Settings, Strategy};
+
+use std::{path::PathBuf, time::Duration};
+
+pub const USAGE: &str = "\
+usage: wordle [play] [--daily | --seed N | --code CODE] [--hard]
+                     [--attempts N | --zen] [--text] [LISTS]
+       wordle solve [--strategy entropy|minimax] [LISTS] [ANSWER]
+       wordle bench [--sample N [--seed N]] [--strategy entropy|minimax]
+                    [--attempts N | --zen] [LISTS]
+       wordle host [--games N] [--seed N] [--timeout MS] [--hard]
+                   [--attempts N | --zen] [LISTS] [--] BOT [ARGS]
+       wordle score GUESS ANSWER
+       wordle stats
+LISTS: [--length 4-8] [--words FILE] [--answers FILE]";
+
+pub enum Command {
+    Play(Settings),
+    /// Guesses read from stdin and feedback printed as text.
+    Text(Settings),
+    Solve(Settings, SolveOptions),
+    Bench(Settings, BenchOptions),
+    /// Games played by an external bot.
+    Host(Settings, HostOptions),
+    Score {
+        guess: String,
+        answer: String,
+    },
+    Stats,
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args: Vec<String> = args.into_iter().collect();
+    let name = match args.first().map(String::as_str) {
+        Some(name @ ("play" | "solve" | "bench" | "host" | "score" | "stats")) => {
+            let name = name.to_string();
+            args.remove(0);
+            name
+        }
+        // the flag `bench` was before it became a subcommand
+        _ => match args.iter().position(|arg| arg == "--bench") {
+            Some(flag) => {
+                args.remove(flag);
+                String::from("bench")
+            }
+            None => String::from("play"),
+        },
+    };
+    let mut args = args.into_iter();
+    let mut settings = Settings::from_env();
+    let mut strategy = Strategy::default();
+    let mut sample = None;
+    let mut seed = None;
+    let mut text = false;
+    let mut host = HostOptions::default();
+    let mut positional = vec![];
+    while let Some(arg) = args.next() {
+        match (name.as_str(), arg.as_str()) {
+            ("play", "--daily") => settings.puzzle = Puzzle::Daily,
+            ("play", "--seed") => settings.puzzle = Puzzle::Seed(parse_seed(&arg, args.next())?),
+            ("play", "--code") => {
+                settings.puzzle =
+                    Puzzle::Code(args.next().ok_or("`--code` needs a value")?.parse()?)
+            }
+            ("play" | "host", "--hard") => settings.hard_mode = true,
+            ("play", "--text") => text = true,
+            ("play" | "bench" | "host", "--attempts") => {
+                settings.max_attempts = Some(parse_number(&arg, args.next())?)
+            }
+            ("play" | "bench" | "host", "--zen") => settings.max_attempts = None,
+            ("play" | "solve" | "bench" | "host", "--length") => {
+                settings.word_length = parse_word_length(&arg, args.next())?
+            }
+            ("play" | "solve" | "bench" | "host", "--words") => {
+                settings.words_path = Some(parse_path(&arg, args.next())?)
+            }
+            ("play" | "solve" | "bench" | "host", "--answers") => {
+                settings.answers_path = Some(parse_path(&arg, args.next())?)
+            }
+            ("bench", "--sample") => sample = Some(parse_number(&arg, args.next())?),
+            ("bench", "--seed") => seed = Some(parse_seed(&arg, args.next())?),
+            ("solve" | "bench", "--strategy") => strategy = parse_strategy(args.next())?,
+            ("host", "--games") => host.games = parse_number(&arg, args.next())?,
+            ("host", "--seed") => host.seed = Some(parse_seed(&arg, args.next())?),
+            ("host", "--timeout") => {
+                host.timeout = Duration::from_millis(parse_number(&arg, args.next())? as u64)
+            }
+            // everything from the bot's program on is its own command line
+            ("host", "--") => {
+                host.bot.extend(args.by_ref());
+                break;
+            }
+            ("host", program) if !program.starts_with('-') => {
+                host.bot.push(arg.clone());
+                host.bot.extend(args.by_ref());
+                break;
+            }
+            (_, flag) if flag.starts_with('-') => {
+                return Err(format!("unknown option `{flag}` for `{name}`"));
+            }
+            _ => positional.push(arg.to_lowercase()),
+        }
+    }
+    match (name.as_str(), positional.as_slice()) {
+        ("play", []) if text && settings.puzzle == Puzzle::Daily => Err(String::from(
+            "`--daily` can only be played once a day, in the interactive game",
+        )),
+        ("play", []) if text => Ok(Command::Text(settings)),
+        ("play", []) => Ok(Command::Play(settings)),
+        ("solve", [] | [_]) => Ok(Command::Solve(
+            settings,
+            SolveOptions {
+                strategy,
+                answer: positional.pop(),
+            },
+        )),
+        ("bench", []) => Ok(Command::Bench(
+            settings,
+            BenchOptions {
+                strategy,
+                sample,
+                seed,
+            },
+        )),
+        ("host", []) if host.bot.is_empty() => Err(String::from("`host` needs a bot to run")),
+        ("host", []) => Ok(Command::Host(settings, host)),
+        ("score", [guess, answer]) => {
+            if !guess.chars().chain(answer.chars()).all(char::is_alphabetic)
+                || guess.chars().count() != answer.chars().count()
+            {
+                return Err(format!(
+                    "`{guess}` and `{answer}` must be words of the same length"
+                ));
+            }
+            Ok(Command::Score {
+                guess: guess.clone(),
+                answer: answer.clone(),
+            })
+        }
+        ("stats", []) => Ok(Command::Stats),
+        (_, [.., extra]) if name != "score" => Err(format!("unexpected argument `{extra}`")),
+        _ => Err(String::from("`score` needs a guess and an answer")),
+    }
+}
+
+fn parse_path(flag: &str, value: Option<String>) -> Result<PathBuf, String> {
+    value
+        .map(PathBuf::from)
+        .ok_or(format!("`{flag}` needs a file"))
+}
+
+fn parse_number(flag: &str, value: Option<String>) -> Result<usize, String> {
+    let value = value.ok_or(format!("`{flag}` needs a value"))?;
+    match value.parse() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("`{flag}` expects a positive number, got `{value}`")),
+    }
+}
+
+fn parse_seed(flag: &str, value: Option<String>) -> Result<u64, String> {
+    let value = value.ok_or(format!("`{flag}` needs a value"))?;
+    value
+        .parse()
+        .map_err(|_| format!("`{flag}` expects a number, got `{value}`"))
+}
+
+fn parse_word_length(flag: &str, value: Option<String>) -> Result<usize, String> {
+    match parse_number(flag, value)? {
+        n @ MIN_WORD_LENGTH..=MAX_WORD_LENGTH => Ok(n),
+        n => Err(format!(
+            "`{flag}` must be between {MIN_WORD_LENGTH} and {MAX_WORD_LENGTH}, got {n}"
+        )),
+    }
+}
+
+fn parse_strategy(value: Option<String>) -> Result<Strategy, String> {
+    match value.as_deref() {
+        Some("entropy") => Ok(Strategy::Entropy),
+        Some("minimax") => Ok(Strategy::Minimax),
+        Some(other) => Err(format!("unknown strategy `{other}`")),
+        None => Err(String::from("`--strategy` needs a value")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_line(line: &str) -> Result<Command, String> {
+        parse(line.split_whitespace().map(String::from))
+    }
+
+    fn error(line: &str) -> String {
+        match parse_line(line) {
+            Ok(_) => panic!("`{line}` should be rejected"),
+            Err(error) => error,
+        }
+    }
+
+    #[test]
+    fn play_is_the_default_command() {
+        let Ok(Command::Play(settings)) = parse_line("--daily --hard --attempts 4") else {
+            panic!("expected play");
+        };
+        assert_eq!(settings.puzzle, Puzzle::Daily);
+        assert!(settings.hard_mode);
+        assert_eq!(settings.max_attempts, Some(4));
+        let Ok(Command::Text(settings)) = parse_line("play --seed 7 --text --zen") else {
+            panic!("expected text");
+        };
+        assert_eq!(settings.puzzle, Puzzle::Seed(7));
+        assert_eq!(settings.max_attempts, None);
+        assert!(error("--daily --text").contains("`--daily`"));
+        assert!(error("--length 9").contains("between 4 and 8"));
+        assert!(error("--attempts 0").contains("positive"));
+        assert_eq!(error("play crane"), "unexpected argument `crane`");
+    }
+
+    #[test]
+    fn flags_belong_to_their_subcommand() {
+        let Ok(Command::Solve(_, options)) = parse_line("solve --strategy minimax CRANE") else {
+            panic!("expected solve");
+        };
+        assert_eq!(options.strategy, Strategy::Minimax);
+        assert_eq!(options.answer.as_deref(), Some("crane"));
+        assert!(matches!(
+            parse_line("solve"),
+            Ok(Command::Solve(_, SolveOptions { answer: None, .. }))
+        ));
+        let Ok(Command::Bench(settings, options)) = parse_line("bench --sample 9 --seed 3 --zen")
+        else {
+            panic!("expected bench");
+        };
+        assert_eq!((options.sample, options.seed), (Some(9), Some(3)));
+        assert_eq!(settings.max_attempts, None);
+        assert!(matches!(parse_line("stats"), Ok(Command::Stats)));
+
+        assert_eq!(error("stats --hard"), "unknown option `--hard` for `stats`");
+        assert_eq!(error("solve --zen"), "unknown option `--zen` for `solve`");
+        assert_eq!(error("bench --text"), "unknown option `--text` for `bench`");
+        assert_eq!(
+            error("bench --daily"),
+            "unknown option `--daily` for `bench`"
+        );
+        assert_eq!(error("solve crane slate"), "unexpected argument `slate`");
+    }
+
+    #[test]
+    fn bench_flag_still_runs_the_benchmark() {
+        let Ok(Command::Bench(_, options)) = parse_line("--sample 5 --bench --strategy minimax")
+        else {
+            panic!("expected bench");
+        };
+        assert_eq!(options.sample, Some(5));
+        assert_eq!(options.strategy, Strategy::Minimax);
+    }
+
+    #[test]
+    fn score_needs_two_words_of_a_length() {
+        let Ok(Command::Score { guess, answer }) = parse_line("score CRANE caper") else {
+            panic!("expected score");
+        };
+        assert_eq!((guess.as_str(), answer.as_str()), ("crane", "caper"));
+        assert_eq!(error("score crane"), "`score` needs a guess and an answer");
+        assert!(error("score crane capers").contains("same length"));
+        assert!(error("score cr4ne caper").contains("same length"));
+    }
+
+    #[test]
+    fn host_passes_the_rest_to_the_bot() {
+        let Ok(Command::Host(settings, options)) =
+            parse_line("host --games 3 --timeout 200 --hard -- ./bot --Fast X")
+        else {
+            panic!("expected host");
+        };
+        assert_eq!(options.bot, ["./bot", "--Fast", "X"]);
+        assert_eq!(options.games, 3);
+        assert_eq!(options.timeout, Duration::from_millis(200));
+        assert!(settings.hard_mode);
+        let Ok(Command::Host(settings, options)) = parse_line("host python3 bot.py --hard") else {
+            panic!("expected host");
+        };
+        assert_eq!(options.bot, ["python3", "bot.py", "--hard"]);
+        assert!(!settings.hard_mode);
+        let Ok(Command::Host(settings, _)) = parse_line("host --zen ./bot") else {
+            panic!("expected host");
+        };
+        assert_eq!(settings.max_attempts, None);
+        assert_eq!(error("host --games 2"), "`host` needs a bot to run");
+        assert_eq!(
+            error("host --text bot"),
+            "unknown option `--text` for `host`"
+        );
+    }
+}
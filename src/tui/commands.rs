@@ -0,0 +1,125 @@
+use std::{
+    io::{self, BufRead},
+    rc::Rc,
+};
+
+use crate::tui::{bench, paths, stats::Stats};
+use wordle::{Config, PatternMatrix, Solver, State, Strategy, WordRevelation};
+
+/// Possibilities listed by `solve` once there are this few left.
+const LISTED_POSSIBILITIES: usize = 10;
+
+#[derive(Default)]
+pub struct SolveOptions {
+    pub strategy: Strategy,
+    /// Word for the solver to find on its own, otherwise the feedback of
+    /// each guess is read from stdin.
+    pub answer: Option<String>,
+}
+
+/// Prints the feedback for `guess` against `answer`.
+pub fn score(guess: &str, answer: &str) {
+    println!("{guess} -> {}", WordRevelation::compute(guess, answer));
+}
+
+/// Runs the solver, either against a known answer or as an assistant for a
+/// game played elsewhere.
+pub fn solve(
+    mut config: Config,
+    matrix: Rc<PatternMatrix>,
+    options: &SolveOptions,
+) -> Result<(), String> {
+    let Some(answer) = &options.answer else {
+        return assist(matrix, options.strategy).map_err(|error| error.to_string());
+    };
+    if !config.choose_word(answer) {
+        return Err(format!("`{answer}` is not one of the possible answers"));
+    }
+    let opener = bench::opener(&matrix, options.strategy);
+    let guesses = bench::solve(&config, &matrix, options.strategy, opener);
+    for guess in &guesses {
+        score(guess, answer);
+    }
+    println!("solved in {}", guesses.len());
+    Ok(())
+}
+
+/// Suggests a guess, then reads back `GUESS FEEDBACK` lines such as
+/// `crane BYBGB` and suggests the next one until the answer is found.
+fn assist(matrix: Rc<PatternMatrix>, strategy: Strategy) -> io::Result<()> {
+    let mut solver = Solver::with_matrix(matrix);
+    suggest(&solver, strategy);
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let mut fields = line.split_whitespace();
+        let Some(guess) = fields.next() else {
+            continue;
+        };
+        let guess = guess.to_lowercase();
+        let feedback: String = fields.collect();
+        let Some(revelation) = WordRevelation::from_feedback(&guess, &feedback) else {
+            eprintln!("expected a guess and its feedback, such as `crane BYBGB`");
+            continue;
+        };
+        if (&revelation).into_iter().all(|r| r.state == State::Correct) {
+            return Ok(());
+        }
+        solver.add_revelations(&revelation);
+        let left = solver.number_of_possibilities();
+        println!("{left} possible words");
+        if left == 0 {
+            return Ok(());
+        }
+        if left <= LISTED_POSSIBILITIES {
+            println!("  {}", solver.possibilities().join(" "));
+        }
+        suggest(&solver, strategy);
+    }
+    Ok(())
+}
+
+fn suggest(solver: &Solver, strategy: Strategy) {
+    if let Some(best) = solver.best_guesses_by(strategy, 1).first() {
+        match strategy {
+            Strategy::Entropy => println!("try: {} ({:.2} bits)", best.word, best.score),
+            Strategy::Minimax => println!("try: {} (at most {} left)", best.word, best.score),
+        }
+    }
+}
+
+/// Prints the stats kept by the game.
+pub fn stats() -> io::Result<()> {
+    let Some(path) = paths::data_dir().map(|dir| dir.join(Stats::FILE_NAME)) else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "no data directory, set XDG_DATA_HOME or HOME",
+        ));
+    };
+    let stats = Stats::load(&path)?;
+    println!(
+        "played {}, won {} ({:.0}%)",
+        stats.played,
+        stats.won,
+        stats.win_rate()
+    );
+    println!(
+        "current streak {}, max streak {}",
+        stats.current_streak, stats.max_streak
+    );
+    let rows = stats.distribution.keys().max().copied().unwrap_or(0);
+    let widest = stats
+        .distribution
+        .values()
+        .copied()
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    for guesses in 1..=rows {
+        let count = stats.distribution.get(&guesses).copied().unwrap_or(0);
+        println!(
+            "{guesses:>3}: {count:>6} {}",
+            "#".repeat(count * 40 / widest)
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+//! The log of daily results.
+
+use crate::tui::paths;
+
+use std::{collections::BTreeMap, io, path::Path};
+
+use time::Date;
+
+/// How the daily puzzle of one day went.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DailyResult {
+    /// Whether the answer was found.
+    pub won: bool,
+    /// Guesses made so far.
+    pub guesses: usize,
+    /// `false` from the first guess until the game is over, so quitting
+    /// halfway doesn't give another try.
+    pub finished: bool,
+}
+
+/// Result of every daily puzzle played, one `date won|lost|started guesses`
+/// line per day in the data directory.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DailyLog {
+    results: BTreeMap<String, DailyResult>,
+}
+
+impl DailyLog {
+    /// Name of the file in the data directory.
+    pub const FILE_NAME: &str = "daily.txt";
+
+    /// Reads the log at `path`, starting afresh when there is none yet.
+    pub fn load(path: &Path) -> io::Result<DailyLog> {
+        match paths::read(path)? {
+            Some(contents) => DailyLog::parse(&contents),
+            None => Ok(DailyLog::default()),
+        }
+    }
+
+    /// Writes the log to `path`, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        paths::write(path, &self.to_string())
+    }
+
+    fn parse(contents: &str) -> io::Result<DailyLog> {
+        let mut log = DailyLog::default();
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let result = match fields.as_slice() {
+                [_, outcome @ ("won" | "lost" | "started"), guesses] => {
+                    guesses.parse().ok().map(|guesses| DailyResult {
+                        won: *outcome == "won",
+                        guesses,
+                        finished: *outcome != "started",
+                    })
+                }
+                _ => None,
+            };
+            let Some(result) = result else {
+                return Err(paths::bad_line("daily", line));
+            };
+            log.results.insert(fields[0].to_string(), result);
+        }
+        Ok(log)
+    }
+
+    /// Result of the puzzle of `date`, `None` if it wasn't played.
+    pub fn get(&self, date: Date) -> Option<DailyResult> {
+        self.results.get(&date.to_string()).copied()
+    }
+
+    /// Sets the result of the puzzle of `date`.
+    pub fn record(&mut self, date: Date, result: DailyResult) {
+        self.results.insert(date.to_string(), result);
+    }
+}
+
+impl std::fmt::Display for DailyLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (date, result) in &self.results {
+            let outcome = match (result.finished, result.won) {
+                (false, _) => "started",
+                (true, true) => "won",
+                (true, false) => "lost",
+            };
+            writeln!(f, "{date} {outcome} {}", result.guesses)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use time::Month;
+
+    #[test]
+    fn log_round_trips() {
+        let date = Date::from_calendar_date(2026, Month::October, 18).unwrap();
+        let mut log = DailyLog::default();
+        log.record(
+            date,
+            DailyResult {
+                won: true,
+                guesses: 4,
+                finished: true,
+            },
+        );
+        assert_eq!(log.to_string(), "2026-10-18 won 4\n");
+        assert_eq!(DailyLog::parse(&log.to_string()).unwrap(), log);
+        let started = "2026-10-18 started 2\n";
+        assert_eq!(DailyLog::parse(started).unwrap().to_string(), started);
+        assert_eq!(log.get(date).map(|result| result.guesses), Some(4));
+        assert!(DailyLog::parse("2026-10-18 drew 4\n").is_err());
+    }
+}
@@ -0,0 +1,783 @@
+use std::{
+    io,
+    path::PathBuf,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    DefaultTerminal, Frame,
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    symbols::border,
+    text::Line,
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::tui::{
+    animation::{Animation, TICK_RATE},
+    board::Board,
+    daily_log::{DailyLog, DailyResult},
+    keyboard::Keyboard,
+    message::{Messages, Severity},
+    paths,
+    save::SavedGame,
+    share,
+    stats::Stats,
+};
+use time::Date;
+use wordle::{
+    Action, Config, Engine, Outcome, PatternMatrix, Puzzle, PuzzleCode, Settings, Status, Strategy,
+    daily,
+};
+
+const TITLE: &str = " Wordle ";
+
+#[derive(Default)]
+pub struct GameState {
+    /// Set when the daily puzzle was already played, so nothing can be
+    /// guessed.
+    locked: bool,
+    /// Clock of the game, started by the first guess.
+    started_at: Option<Instant>,
+    finished_at: Option<Instant>,
+}
+
+pub struct Game {
+    engine: Engine,
+    game_state: GameState,
+    stats: Stats,
+    /// Where `stats` is saved, `None` when it couldn't be read and must not
+    /// be overwritten.
+    stats_path: Option<PathBuf>,
+    /// Shareable name of the word being played.
+    code: PuzzleCode,
+    /// Date of the puzzle in daily mode.
+    daily: Option<Date>,
+    daily_log: DailyLog,
+    /// Where `daily_log` is saved, `None` when it couldn't be read.
+    daily_log_path: Option<PathBuf>,
+    show_stats: bool,
+    /// Where stats, daily results and the saved game are kept.
+    data_dir: Option<PathBuf>,
+    /// Unfinished game from the last session, waiting for the player to
+    /// resume or discard it.
+    saved: Option<SavedGame>,
+    messages: Messages,
+    animation: Option<Animation>,
+    exit: bool,
+}
+
+impl Game {
+    fn new(config: Config, settings: Settings, data_dir: Option<PathBuf>) -> Game {
+        let mut game = Game {
+            code: PuzzleCode::new(config.answers(), config.answer_index()),
+            engine: Engine::new(config, settings),
+            game_state: GameState::default(),
+            stats: Stats::default(),
+            stats_path: None,
+            daily: None,
+            daily_log: DailyLog::default(),
+            daily_log_path: None,
+            show_stats: false,
+            data_dir,
+            saved: None,
+            messages: Messages::default(),
+            animation: None,
+            exit: false,
+        };
+        if let Some(path) = game.data_path(Stats::FILE_NAME) {
+            match Stats::load(&path) {
+                Ok(stats) => {
+                    game.stats = stats;
+                    game.stats_path = Some(path);
+                }
+                Err(error) => {
+                    game.messages.push_sticky(
+                        Severity::Error,
+                        format!("Can't read {}: {error}", path.display()),
+                    );
+                }
+            }
+        }
+        game
+    }
+
+    pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        let mut last_tick = Instant::now();
+        while !self.exit {
+            terminal.draw(|frame| self.draw(frame))?;
+            let timeout = TICK_RATE.saturating_sub(last_tick.elapsed());
+            if event::poll(timeout)? {
+                self.handle_events()?;
+            }
+            if last_tick.elapsed() >= TICK_RATE {
+                self.tick();
+                last_tick = Instant::now();
+            }
+        }
+        self.save_progress()
+    }
+
+    /// Called every `TICK_RATE` whether or not a key was pressed.
+    fn tick(&mut self) {
+        let now = Instant::now();
+        if self
+            .animation
+            .is_some_and(|animation| animation.is_done(now))
+        {
+            self.animation = None;
+        }
+        self.messages.expire(now);
+    }
+
+    pub fn start(settings: Settings) -> Game {
+        Game::open(settings, daily::today(), None, paths::data_dir())
+            .expect("only a given answer can be missing")
+    }
+
+    /// Game of `settings` playing `answer` instead of the puzzle's word when
+    /// given, `date` being the day of a daily puzzle. `None` when `answer` is
+    /// not in the answer list.
+    fn open(
+        settings: Settings,
+        date: Date,
+        answer: Option<&str>,
+        data_dir: Option<PathBuf>,
+    ) -> Option<Game> {
+        let mut errors = vec![];
+        let mut config = Config::load(&settings).unwrap_or_else(|error| {
+            errors.push(format!("{error}, using the built-in word lists"));
+            Config::embedded()
+        });
+        let index = match answer {
+            Some(answer) => Some(config.answers().iter().position(|word| word == answer)?),
+            None => settings
+                .puzzle
+                .answer_index(config.answers(), date)
+                .unwrap_or_else(|error| {
+                    errors.push(format!("{error}, playing a random word"));
+                    None
+                }),
+        };
+        if let Some(index) = index {
+            config.choose_answer(index);
+        }
+        let daily = settings.puzzle == Puzzle::Daily;
+        let mut game = Game::new(config, settings, data_dir);
+        for error in errors {
+            game.messages.push_sticky(Severity::Error, error);
+        }
+        if daily {
+            game.open_daily(date, answer.is_some());
+        }
+        Some(game)
+    }
+
+    /// Path of a file in the data directory, `None` when there is none.
+    fn data_path(&self, file_name: &str) -> Option<PathBuf> {
+        self.data_dir.as_deref().map(|dir| dir.join(file_name))
+    }
+
+    /// Plays the puzzle of `date`, unless it is in the log already: finished,
+    /// or started and not being resumed. Without a log it can't be played
+    /// only once, so it isn't played at all.
+    fn open_daily(&mut self, date: Date, resuming: bool) {
+        self.daily = Some(date);
+        let Some(path) = self.data_path(DailyLog::FILE_NAME) else {
+            self.game_state.locked = true;
+            self.messages.push_sticky(
+                Severity::Error,
+                "The daily needs a data directory to be logged in, set XDG_DATA_HOME",
+            );
+            return;
+        };
+        match DailyLog::load(&path) {
+            Ok(log) => {
+                self.daily_log = log;
+                self.daily_log_path = Some(path);
+            }
+            Err(error) => {
+                self.game_state.locked = true;
+                self.messages.push_sticky(
+                    Severity::Error,
+                    format!("Can't read {}: {error}", path.display()),
+                );
+                return;
+            }
+        }
+        if let Some(result) = self.daily_log.get(date)
+            && (result.finished || !resuming)
+        {
+            self.game_state.locked = true;
+            let outcome = match (result.finished, result.won) {
+                (false, _) => String::from("left unfinished"),
+                (true, true) => format!("solved in {}", result.guesses),
+                (true, false) => String::from("lost"),
+            };
+            self.messages.push_sticky(
+                Severity::Info,
+                format!(
+                    "Daily #{} already played ({outcome}), come back tomorrow",
+                    daily::puzzle_number(date)
+                ),
+            );
+        }
+    }
+
+    /// Offers to resume the game left unfinished last time, if any.
+    pub fn offer_saved_game(&mut self) {
+        let Some(path) = self.data_path(SavedGame::FILE_NAME) else {
+            return;
+        };
+        match SavedGame::load(&path) {
+            Ok(saved) => self.saved = saved,
+            Err(error) => self.messages.push_sticky(
+                Severity::Error,
+                format!("Can't read {}: {error}", path.display()),
+            ),
+        }
+    }
+
+    /// Replaces this game by the saved one, replaying its guesses. Keeps the
+    /// new game when the saved word is no longer in the list.
+    fn resume(&mut self, saved: SavedGame) {
+        let date = saved.daily.unwrap_or_else(daily::today);
+        let Some(mut game) = Game::open(
+            saved.settings,
+            date,
+            Some(&saved.answer),
+            self.data_dir.clone(),
+        ) else {
+            self.messages.push_sticky(
+                Severity::Error,
+                "The saved word is no longer in the word list, starting a new game",
+            );
+            return;
+        };
+        if !game.finished() {
+            for guess in saved.guesses {
+                for c in guess.chars() {
+                    game.engine.apply(Action::Type(c));
+                }
+                game.apply(Action::Submit);
+            }
+            game.animation = None;
+            game.game_state.started_at = Instant::now().checked_sub(saved.elapsed);
+        }
+        *self = game;
+    }
+
+    /// Keeps an unfinished game for the next launch and forgets a finished
+    /// one.
+    fn save_progress(&self) -> io::Result<()> {
+        // the offered game stays on disk until the player picks
+        let (Some(path), None) = (self.data_path(SavedGame::FILE_NAME), &self.saved) else {
+            return Ok(());
+        };
+        let revelations = self.engine.revelations();
+        if self.finished() || revelations.is_empty() {
+            return SavedGame::discard(&path);
+        }
+        SavedGame {
+            settings: self.engine.settings().clone(),
+            daily: self.daily,
+            answer: self.engine.config().chosen_word().to_string(),
+            guesses: revelations
+                .iter()
+                .map(|revelation| revelation.into_iter().map(|r| r.letter).collect())
+                .collect(),
+            elapsed: self.elapsed(),
+        }
+        .save(&path)
+    }
+
+    fn handle_saved_game_key(&mut self, code: KeyCode) {
+        let resume = match code {
+            KeyCode::Char('y') => true,
+            KeyCode::Char('n') => false,
+            _ => return,
+        };
+        let Some(saved) = self.saved.take() else {
+            return;
+        };
+        if let Some(path) = self.data_path(SavedGame::FILE_NAME)
+            && let Err(error) = SavedGame::discard(&path)
+        {
+            self.messages.push(
+                Severity::Error,
+                format!("Can't remove the saved game: {error}"),
+            );
+        }
+        if resume {
+            self.resume(saved);
+        }
+    }
+
+    fn restart(&mut self) {
+        if self.daily.is_some() {
+            self.messages
+                .push(Severity::Error, "The daily puzzle can't be restarted");
+            return;
+        }
+        self.engine.apply(Action::Restart);
+        let config = self.engine.config();
+        self.code = PuzzleCode::new(config.answers(), config.answer_index());
+        self.game_state = GameState::default();
+        self.messages = Messages::default();
+        self.animation = None;
+    }
+
+    /// Builds the guess/answer pattern matrix the first time a hint is asked
+    /// for, reusing the on-disk cache when there is one.
+    fn ensure_matrix(&mut self) {
+        if self.engine.has_matrix() {
+            return;
+        }
+        let config = self.engine.config();
+        let matrix = Rc::new(PatternMatrix::load_or_build(
+            config,
+            paths::cache_dir().as_deref(),
+        ));
+        self.engine.attach_matrix(matrix);
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        frame.render_widget(self, frame.area());
+    }
+
+    fn handle_events(&mut self) -> io::Result<()> {
+        match event::read()? {
+            // it's important to check that the event is a key press event as
+            // crossterm also emits key release and repeat events on Windows.
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                self.handle_key_event(key_event)
+            }
+            _ => {}
+        };
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Esc, _) => self.exit(),
+            (code, _) if self.saved.is_some() => {
+                self.handle_saved_game_key(code);
+                return;
+            }
+            (KeyCode::Char('r'), KeyModifiers::CONTROL) => self.restart(),
+            (KeyCode::Char('s'), KeyModifiers::CONTROL) => {
+                self.show_stats = !self.show_stats;
+                return;
+            }
+            _ => {}
+        }
+        if self.show_stats {
+            return;
+        }
+        if self.finished() {
+            if key_event.code == KeyCode::Char('c') {
+                self.share();
+            }
+            return;
+        }
+        match key_event.code {
+            // shortcuts such as Ctrl-r must not also type their letter
+            KeyCode::Char(_)
+                if key_event
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {}
+            KeyCode::Char(c) => self.apply(Action::Type(c)),
+            KeyCode::Delete | KeyCode::Backspace => self.apply(Action::Delete),
+            KeyCode::Enter => self.apply(Action::Submit),
+            KeyCode::Tab => self.suggest_guess(Strategy::Entropy),
+            KeyCode::BackTab => self.suggest_guess(Strategy::Minimax),
+            _ => {}
+        }
+    }
+
+    /// Plays `action` and shows what came of it.
+    fn apply(&mut self, action: Action) {
+        let outcome = self.engine.apply(action);
+        match outcome {
+            Outcome::Accepted | Outcome::Won { .. } | Outcome::Lost { .. } => {
+                self.game_state.started_at.get_or_insert(Instant::now());
+                self.animation = Some(Animation::reveal(self.engine.config().word_length()));
+                if outcome == Outcome::Accepted {
+                    // the daily counts as played from the first guess
+                    self.record_daily();
+                } else {
+                    self.finish();
+                }
+                return;
+            }
+            _ => {}
+        }
+        let Some(message) = outcome.message() else {
+            return;
+        };
+        self.messages.push(Severity::Error, message);
+        if action == Action::Submit {
+            self.animation = Some(Animation::shake());
+        }
+    }
+
+    fn suggest_guess(&mut self, strategy: Strategy) {
+        self.ensure_matrix();
+        if let Some(best) = self.engine.solver().best_guesses_by(strategy, 1).first() {
+            let hint = match strategy {
+                Strategy::Entropy => format!("Try: {} ({:.2} bits)", best.word, best.score),
+                Strategy::Minimax => format!("Try: {} (at most {} left)", best.word, best.score),
+            };
+            self.messages.push(Severity::Hint, hint);
+        }
+    }
+    /// Guess number to display: the one being typed, or the last one once the
+    /// game is over.
+    fn guesses_shown(&self) -> usize {
+        if self.finished() {
+            self.engine.attempts()
+        } else {
+            self.engine.attempts() + 1
+        }
+    }
+
+    /// Whether guessing is over, the game being won, lost or locked.
+    fn finished(&self) -> bool {
+        self.engine.is_over() || self.game_state.locked
+    }
+
+    /// Time spent on the game so far, frozen once it is over.
+    fn elapsed(&self) -> Duration {
+        match (self.game_state.started_at, self.game_state.finished_at) {
+            (Some(started), Some(finished)) => finished.duration_since(started),
+            (Some(started), None) => started.elapsed(),
+            _ => Duration::ZERO,
+        }
+    }
+
+    fn exit(&mut self) {
+        self.exit = true;
+    }
+
+    fn finish(&mut self) {
+        self.game_state.finished_at = Some(Instant::now());
+        if self.engine.status() == Status::Won {
+            self.messages
+                .push_sticky(Severity::Success, "Congratulations");
+        } else if let Some(summary) = self.engine.summary() {
+            self.messages.push_sticky(Severity::Info, summary);
+        }
+        self.messages
+            .push_sticky(Severity::Info, "Press c to copy your result");
+        self.record_stats();
+        self.record_daily();
+    }
+
+    /// Copies the result grid so it can be pasted in chat.
+    fn share(&mut self) {
+        let revelations = self.engine.revelations();
+        if revelations.is_empty() {
+            return;
+        }
+        let name = match self.daily {
+            Some(date) => daily::puzzle_number(date).to_string(),
+            None => self.code.to_string(),
+        };
+        let text = share::summary(
+            &name,
+            revelations,
+            self.engine.status() == Status::Won,
+            self.engine.settings().max_attempts,
+            self.engine.settings().hard_mode,
+        );
+        match share::copy_to_clipboard(&text) {
+            Ok(()) => self
+                .messages
+                .push(Severity::Success, "Result copied to the clipboard"),
+            Err(error) => self
+                .messages
+                .push(Severity::Error, format!("Can't copy the result: {error}")),
+        }
+    }
+
+    fn record_stats(&mut self) {
+        self.stats
+            .record(self.engine.status() == Status::Won, self.engine.attempts());
+        let Some(path) = &self.stats_path else {
+            return;
+        };
+        if let Err(error) = self.stats.save(path) {
+            self.messages
+                .push_sticky(Severity::Error, format!("Stats not saved: {error}"));
+        }
+    }
+
+    fn record_daily(&mut self) {
+        let Some(date) = self.daily else {
+            return;
+        };
+        self.daily_log.record(
+            date,
+            DailyResult {
+                won: self.engine.status() == Status::Won,
+                guesses: self.engine.attempts(),
+                finished: self.engine.is_over(),
+            },
+        );
+        let Some(path) = &self.daily_log_path else {
+            return;
+        };
+        if let Err(error) = self.daily_log.save(path) {
+            self.messages
+                .push_sticky(Severity::Error, format!("Daily result not saved: {error}"));
+        }
+    }
+}
+
+impl Game {
+    fn stats_lines(&self) -> Vec<Line<'_>> {
+        let stats = &self.stats;
+        let mut lines = vec![
+            Line::from(" Statistics ".bold()),
+            Line::from(format!(
+                " Played {} - Win % {:.0} - Current streak {} - Max streak {} ",
+                stats.played,
+                stats.win_rate(),
+                stats.current_streak,
+                stats.max_streak
+            )),
+            Line::from(""),
+            Line::from(" Guess distribution ".bold()),
+        ];
+        let rows = self
+            .engine
+            .settings()
+            .max_attempts
+            .or(stats.distribution.keys().max().copied())
+            .unwrap_or(0);
+        let widest = stats
+            .distribution
+            .values()
+            .copied()
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        for guesses in 1..=rows {
+            let count = stats.distribution.get(&guesses).copied().unwrap_or(0);
+            let bar = "#".repeat(count * 30 / widest);
+            lines.push(Line::from(format!(" {guesses} {bar:<30} {count:>4} ")));
+        }
+        lines
+    }
+}
+
+fn saved_game_lines(saved: &SavedGame) -> Vec<Line<'static>> {
+    let seconds = saved.elapsed.as_secs();
+    let guesses = match saved.settings.max_attempts {
+        Some(max) => format!("{}/{max}", saved.guesses.len()),
+        None => saved.guesses.len().to_string(),
+    };
+    vec![
+        Line::from(" Unfinished game ".bold()),
+        Line::from(format!(
+            " {guesses} guesses made in {}:{:02} ",
+            seconds / 60,
+            seconds % 60
+        )),
+        Line::from(""),
+        Line::from(vec![
+            " Resume it ".into(),
+            "<y> ".green().bold(),
+            " - ".bold(),
+            " Start a new game ".into(),
+            "<n> ".red().bold(),
+        ]),
+    ]
+}
+
+impl Widget for &Game {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let title = Line::from(TITLE.bold());
+        let instructions = Line::from(vec![
+            " Quit ".into(),
+            "<Esc> ".blue().bold(),
+            " - ".bold(),
+            " Hint ".into(),
+            "<Tab>/<Shift-Tab> ".green().bold(),
+            " - ".bold(),
+            " Restart ".into(),
+            "<Ctrl-r> ".red().bold(),
+            " - ".bold(),
+            " Stats ".into(),
+            "<Ctrl-s> ".magenta().bold(),
+        ]);
+        let block = Block::bordered()
+            .title(title.centered())
+            .title_bottom(instructions.centered())
+            .border_set(border::THICK);
+        if let Some(saved) = &self.saved {
+            Paragraph::new(saved_game_lines(saved))
+                .centered()
+                .block(block)
+                .render(area, buf);
+            return;
+        }
+        if self.show_stats {
+            Paragraph::new(self.stats_lines())
+                .centered()
+                .block(block)
+                .render(area, buf);
+            return;
+        }
+        let mut status = match &self.engine.config().file_path() {
+            Some(path) => format!(
+                " {} possible words from {}",
+                self.engine.solver().number_of_possibilities(),
+                path.display()
+            ),
+            None => format!(
+                " {} possible words",
+                self.engine.solver().number_of_possibilities()
+            ),
+        };
+        match self.engine.settings().max_attempts {
+            Some(max) => status.push_str(&format!(" - guess {}/{max}", self.guesses_shown())),
+            None => status.push_str(&format!(" - guess {} - zen mode", self.guesses_shown())),
+        }
+        match self.daily {
+            Some(date) => status.push_str(&format!(" - daily #{}", daily::puzzle_number(date))),
+            None => status.push_str(&format!(" - puzzle {}", self.code)),
+        }
+        if self.engine.settings().hard_mode {
+            status.push_str(" - hard mode");
+        }
+        let seconds = self.elapsed().as_secs();
+        status.push_str(&format!(" - {}:{:02} ", seconds / 60, seconds % 60));
+        let revelations = self.engine.revelations();
+        let now = Instant::now();
+        let flipped = self.animation.and_then(|animation| animation.flipped(now));
+        let board = Board {
+            revelations,
+            guess: self.engine.guess(),
+            word_length: self.engine.config().word_length(),
+            max_attempts: self.engine.settings().max_attempts,
+            finished: self.finished(),
+            flipped,
+            shake: self
+                .animation
+                .map_or(0, |animation| animation.shake_offset(now)),
+        };
+        // keys only light up once the guess that revealed them has flipped
+        let known = match flipped {
+            Some(_) => &revelations[..revelations.len().saturating_sub(1)],
+            None => revelations,
+        };
+
+        let keyboard =
+            Keyboard::from_revelations(known).with_alphabet(self.engine.config().alphabet());
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let [status_area, board_area, messages_area, keyboard_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(self.messages.height()),
+            Constraint::Length(keyboard.height()),
+        ])
+        .spacing(1)
+        .areas(inner);
+        Line::from(status).centered().render(status_area, buf);
+        board.render(board_area, buf);
+        self.messages.render(messages_area, buf);
+        keyboard.render(keyboard_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{env, fs, process};
+    use time::Month;
+
+    fn data_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("wordle-game-{name}-{}", process::id()))
+    }
+
+    fn date() -> Date {
+        Date::from_calendar_date(2026, Month::October, 18).unwrap()
+    }
+
+    /// Plays a word that isn't the answer.
+    fn guess_wrong(game: &mut Game) {
+        let config = game.engine.config();
+        let word = config
+            .answers()
+            .iter()
+            .find(|word| *word != config.chosen_word())
+            .unwrap()
+            .clone();
+        for c in word.chars() {
+            game.apply(Action::Type(c));
+        }
+        game.apply(Action::Submit);
+    }
+
+    #[test]
+    fn quitting_the_daily_uses_it_up() {
+        let dir = data_dir("daily");
+        let settings = Settings {
+            puzzle: Puzzle::Daily,
+            ..Settings::default()
+        };
+        let mut game = Game::open(settings.clone(), date(), None, Some(dir.clone())).unwrap();
+        guess_wrong(&mut game);
+        let answer = game.engine.config().chosen_word().to_string();
+        assert!(!game.finished());
+        // quitting with Esc doesn't finish the game
+        drop(game);
+
+        let reopened = Game::open(settings.clone(), date(), None, Some(dir.clone())).unwrap();
+        assert!(reopened.finished());
+        let resumed = Game::open(settings, date(), Some(&answer), Some(dir.clone())).unwrap();
+        assert!(!resumed.finished());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn the_daily_needs_a_log() {
+        let settings = Settings {
+            puzzle: Puzzle::Daily,
+            ..Settings::default()
+        };
+        let game = Game::open(settings, date(), None, None).unwrap();
+        assert!(game.finished());
+        assert!(
+            game.messages
+                .iter()
+                .any(|m| m.text.contains("XDG_DATA_HOME"))
+        );
+    }
+
+    #[test]
+    fn saves_of_removed_words_start_a_new_game() {
+        let dir = data_dir("resume");
+        let mut game = Game::open(Settings::default(), date(), None, Some(dir.clone())).unwrap();
+        let answer = game.engine.config().chosen_word().to_string();
+        game.resume(SavedGame {
+            settings: Settings::default(),
+            daily: None,
+            answer: String::from("zzzzz"),
+            guesses: vec![String::from("crane"), String::from("slate")],
+            elapsed: Duration::from_secs(30),
+        });
+        assert_eq!(game.engine.attempts(), 0);
+        assert_eq!(game.engine.config().chosen_word(), answer);
+        assert!(game.messages.iter().any(|m| m.text.contains("no longer")));
+        assert_eq!(game.stats, Stats::default());
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,408 @@
+use std::{
+    fmt,
+    io::{self, BufRead, BufReader, Write},
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use rand::{Rng, rng};
+use wordle::{
+    Config, Engine, Outcome, Puzzle, Settings, daily,
+    protocol::{BotMessage, HostMessage, VERSION, ZEN_GUESS_LIMIT},
+};
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct HostOptions {
+    /// Program and arguments of the bot.
+    pub bot: Vec<String>,
+    pub games: usize,
+    /// Game `n` plays the answer of seed `seed + n`, otherwise answers are
+    /// random.
+    pub seed: Option<u64>,
+    /// Time the bot has to answer each message.
+    pub timeout: Duration,
+}
+
+impl Default for HostOptions {
+    fn default() -> HostOptions {
+        HostOptions {
+            bot: vec![],
+            games: 1,
+            seed: None,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GameResult {
+    Won(usize),
+    Lost,
+    /// The bot made a guess the rules reject.
+    Invalid(String),
+    /// The bot didn't answer within the timeout.
+    TimedOut(Duration),
+    /// The bot exited or sent something that isn't part of the protocol.
+    Broken(String),
+}
+
+impl GameResult {
+    /// Whether the bot can't be trusted to play another game.
+    fn ends_match(&self) -> bool {
+        matches!(self, GameResult::TimedOut(_) | GameResult::Broken(_))
+    }
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameResult::Won(guesses) => write!(f, "won in {guesses}"),
+            GameResult::Lost => write!(f, "lost"),
+            GameResult::Invalid(reason) | GameResult::Broken(reason) => write!(f, "{reason}"),
+            GameResult::TimedOut(timeout) => write!(f, "no answer within {timeout:.1?}"),
+        }
+    }
+}
+
+pub struct MatchReport {
+    /// Name the bot gave, or its command line.
+    pub name: String,
+    /// Answer of each game played and how it ended.
+    pub results: Vec<(String, GameResult)>,
+    pub slowest_move: Duration,
+    pub elapsed: Duration,
+}
+
+impl MatchReport {
+    fn count(&self, matches: impl Fn(&GameResult) -> bool) -> usize {
+        self.results
+            .iter()
+            .filter(|(_, result)| matches(result))
+            .count()
+    }
+}
+
+impl fmt::Display for MatchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let won: Vec<usize> = self
+            .results
+            .iter()
+            .filter_map(|(_, result)| match result {
+                GameResult::Won(guesses) => Some(*guesses),
+                _ => None,
+            })
+            .collect();
+        writeln!(
+            f,
+            "{}: {} games in {:.1?}, {} won, {} lost, {} invalid",
+            self.name,
+            self.results.len(),
+            self.elapsed,
+            won.len(),
+            self.count(|result| *result == GameResult::Lost),
+            self.count(|result| matches!(result, GameResult::Invalid(_))),
+        )?;
+        if !won.is_empty() {
+            let average = won.iter().sum::<usize>() as f64 / won.len() as f64;
+            writeln!(f, "average guesses in won games: {average:.3}")?;
+        }
+        writeln!(f, "slowest move: {:.1?}", self.slowest_move)?;
+        for (answer, result) in &self.results {
+            match result {
+                GameResult::Won(_) => {}
+                result if result.ends_match() => {
+                    writeln!(f, "  {answer}: {result}, match stopped")?
+                }
+                result => writeln!(f, "  {answer}: {result}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Bot process talking the protocol on its stdin and stdout.
+struct Bot {
+    child: Child,
+    stdin: ChildStdin,
+    lines: Receiver<io::Result<String>>,
+    timeout: Duration,
+}
+
+impl Bot {
+    fn spawn(command: &[String], timeout: Duration) -> io::Result<Bot> {
+        let (program, args) = command
+            .split_first()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no bot to run"))?;
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = child.stdout.take().expect("stdout is piped");
+        // reading on a thread lets `receive` give up after the timeout
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(Bot {
+            child,
+            stdin,
+            lines,
+            timeout,
+        })
+    }
+
+    fn send(&mut self, message: &HostMessage) -> Result<(), GameResult> {
+        writeln!(self.stdin, "{message}")
+            .and_then(|()| self.stdin.flush())
+            .map_err(|error| GameResult::Broken(format!("can't write to the bot: {error}")))
+    }
+
+    /// Next message of the bot and how long it took to come.
+    fn receive(&mut self) -> Result<(BotMessage, Duration), GameResult> {
+        let started = Instant::now();
+        match self.lines.recv_timeout(self.timeout) {
+            Ok(Ok(line)) => line
+                .parse()
+                .map(|message| (message, started.elapsed()))
+                .map_err(GameResult::Broken),
+            Ok(Err(error)) => Err(GameResult::Broken(format!("can't read the bot: {error}"))),
+            Err(RecvTimeoutError::Timeout) => Err(GameResult::TimedOut(self.timeout)),
+            Err(RecvTimeoutError::Disconnected) => {
+                Err(GameResult::Broken(String::from("the bot exited")))
+            }
+        }
+    }
+}
+
+impl Drop for Bot {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Plays `options.games` games of `settings` against the bot, with `config`
+/// as the oracle. The match stops early if the bot times out or breaks the
+/// protocol.
+pub fn run(config: Config, settings: Settings, options: &HostOptions) -> io::Result<MatchReport> {
+    let started = Instant::now();
+    let mut bot = Bot::spawn(&options.bot, options.timeout)?;
+    let mut report = MatchReport {
+        name: options.bot.join(" "),
+        results: vec![],
+        slowest_move: Duration::ZERO,
+        elapsed: Duration::ZERO,
+    };
+    let greeting = bot
+        .send(&HostMessage::Hello { version: VERSION })
+        .and_then(|()| bot.receive());
+    match greeting {
+        Ok((BotMessage::Ready { name }, _)) => {
+            if let Some(name) = name {
+                report.name = name;
+            }
+        }
+        Ok((message, _)) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("expected `ready` from the bot, got `{message}`"),
+            ));
+        }
+        Err(result) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("the bot didn't answer the greeting: {result}"),
+            ));
+        }
+    }
+    let answers = config.answers().len();
+    for game in 0..options.games {
+        let mut config = config.clone();
+        let puzzle = match options.seed {
+            Some(seed) => Puzzle::Seed(seed.wrapping_add(game as u64)),
+            None => Puzzle::Random,
+        };
+        match puzzle.answer_index(config.answers(), daily::today()) {
+            Ok(Some(index)) => config.choose_answer(index),
+            _ => config.choose_answer(rng().random_range(0..answers)),
+        }
+        let answer = config.chosen_word().to_string();
+        let mut engine = Engine::new(config, settings.clone());
+        let result = play_game(&mut bot, &mut engine, &mut report.slowest_move);
+        let ends_match = result.ends_match();
+        report.results.push((answer, result));
+        if ends_match {
+            break;
+        }
+    }
+    let _ = bot.send(&HostMessage::Quit);
+    report.elapsed = started.elapsed();
+    Ok(report)
+}
+
+fn play_game(bot: &mut Bot, engine: &mut Engine, slowest_move: &mut Duration) -> GameResult {
+    let game = HostMessage::Game {
+        word_length: engine.config().word_length(),
+        max_attempts: engine.settings().max_attempts,
+    };
+    if let Err(result) = bot.send(&game) {
+        return result;
+    }
+    loop {
+        let word = match bot.receive() {
+            Ok((BotMessage::Guess(word), took)) => {
+                *slowest_move = (*slowest_move).max(took);
+                word
+            }
+            Ok((message, _)) => {
+                return GameResult::Broken(format!("expected a guess, got `{message}`"));
+            }
+            Err(result) => return result,
+        };
+        let outcome = engine.play(&word);
+        let mut replies = vec![];
+        let result = match &outcome {
+            // zen games still need an end
+            Outcome::Accepted
+                if engine.settings().max_attempts.is_none()
+                    && engine.attempts() >= ZEN_GUESS_LIMIT =>
+            {
+                Some(GameResult::Lost)
+            }
+            Outcome::Accepted => None,
+            Outcome::Won { guesses } => Some(GameResult::Won(*guesses)),
+            Outcome::Lost { .. } => Some(GameResult::Lost),
+            rejected => {
+                let reason = rejected.message().unwrap_or_default();
+                replies.push(HostMessage::Invalid(reason.clone()));
+                Some(GameResult::Invalid(format!("{word}: {reason}")))
+            }
+        };
+        if let Some(revelation) = engine.revelations().last()
+            && matches!(
+                outcome,
+                Outcome::Accepted | Outcome::Won { .. } | Outcome::Lost { .. }
+            )
+        {
+            replies.insert(0, HostMessage::feedback(revelation));
+        }
+        if let Some(result) = &result {
+            replies.push(HostMessage::Result {
+                won: matches!(result, GameResult::Won(_)),
+                guesses: engine.attempts(),
+                answer: engine.config().chosen_word().to_string(),
+            });
+        }
+        for reply in &replies {
+            if let Err(result) = bot.send(reply) {
+                return result;
+            }
+        }
+        if let Some(result) = result {
+            return result;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        let words: Vec<String> = ["crane", "slate"].map(String::from).into();
+        Config::new(None, words, vec![String::from("slate")]).unwrap()
+    }
+
+    fn shell_bot(script: &str, games: usize, timeout: Duration) -> HostOptions {
+        HostOptions {
+            bot: ["sh", "-c", script].map(String::from).into(),
+            games,
+            seed: Some(1),
+            timeout,
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn bots_play_through_the_protocol() {
+        let options = shell_bot(
+            "read hello; echo ready shell bot
+             while read message; do
+                 case $message in
+                     game*) echo guess crane ;;
+                     \"feedback GGGGG\") ;;
+                     feedback*) echo guess slate ;;
+                     quit) exit ;;
+                 esac
+             done",
+            2,
+            DEFAULT_TIMEOUT,
+        );
+        let report = run(config(), Settings::default(), &options).unwrap();
+        assert_eq!(report.name, "shell bot");
+        assert_eq!(
+            report.results,
+            vec![
+                (String::from("slate"), GameResult::Won(2)),
+                (String::from("slate"), GameResult::Won(2)),
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn zen_games_end_at_the_guess_limit() {
+        let options = shell_bot(
+            "read hello; echo ready
+             while read message; do
+                 case $message in
+                     game*|feedback*) echo guess crane ;;
+                     quit) exit ;;
+                 esac
+             done",
+            1,
+            DEFAULT_TIMEOUT,
+        );
+        let settings = Settings {
+            max_attempts: None,
+            ..Settings::default()
+        };
+        let report = run(config(), settings, &options).unwrap();
+        assert_eq!(
+            report.results,
+            vec![(String::from("slate"), GameResult::Lost)]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn slow_bots_stop_the_match() {
+        let options = shell_bot(
+            "read hello; echo ready; read game; sleep 2",
+            3,
+            Duration::from_millis(200),
+        );
+        let report = run(config(), Settings::default(), &options).unwrap();
+        assert_eq!(
+            report.results,
+            vec![(
+                String::from("slate"),
+                GameResult::TimedOut(Duration::from_millis(200))
+            )]
+        );
+        assert!(
+            report
+                .to_string()
+                .contains("slate: no answer within 200.0ms, match stopped")
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+use wordle::{State, WordRevelation};
+
+const ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+const EXTRA_KEYS_PER_ROW: usize = 10;
+
+/// Best state known for every letter guessed so far.
+#[derive(Default)]
+pub struct Keyboard {
+    states: HashMap<char, State>,
+    /// Letters of the word list missing from QWERTY, such as accented ones.
+    extra: Vec<char>,
+}
+
+impl Keyboard {
+    pub fn from_revelations<'a>(
+        revelations: impl IntoIterator<Item = &'a WordRevelation>,
+    ) -> Keyboard {
+        let mut states: HashMap<char, State> = HashMap::new();
+        for revelation in revelations {
+            for r in revelation {
+                let known = states.entry(r.letter).or_insert(r.state.clone());
+                if rank(&r.state) > rank(known) {
+                    *known = r.state.clone();
+                }
+            }
+        }
+        Keyboard {
+            states,
+            extra: vec![],
+        }
+    }
+
+    /// Adds rows for the letters of `alphabet` that QWERTY doesn't have.
+    pub fn with_alphabet<'a>(mut self, alphabet: impl IntoIterator<Item = &'a char>) -> Keyboard {
+        self.extra = alphabet
+            .into_iter()
+            .filter(|letter| !ROWS.iter().any(|row| row.contains(**letter)))
+            .copied()
+            .collect();
+        self
+    }
+
+    /// Lines the keyboard needs when rendered.
+    pub fn height(&self) -> u16 {
+        (ROWS.len() + self.extra.len().div_ceil(EXTRA_KEYS_PER_ROW)) as u16
+    }
+
+    pub fn state(&self, letter: char) -> Option<&State> {
+        self.states.get(&letter)
+    }
+
+    fn key(&self, letter: char) -> Span<'static> {
+        let style = match self.state(letter) {
+            Some(State::Correct) => Style::new().black().on_green(),
+            Some(State::Change) => Style::new().black().on_yellow(),
+            Some(State::Wrong) => Style::new().dark_gray(),
+            None => Style::new().white(),
+        };
+        Span::styled(format!(" {} ", letter.to_uppercase()), style.bold())
+    }
+}
+
+/// Correct beats Change beats Wrong.
+fn rank(state: &State) -> u8 {
+    match state {
+        State::Wrong => 0,
+        State::Change => 1,
+        State::Correct => 2,
+    }
+}
+
+impl Widget for &Keyboard {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let extra_rows = self.extra.chunks(EXTRA_KEYS_PER_ROW);
+        let lines: Vec<Line> = ROWS
+            .iter()
+            .map(|row| row.chars().map(|c| self.key(c)).collect::<Vec<_>>())
+            .chain(extra_rows.map(|row| row.iter().map(|c| self.key(*c)).collect()))
+            .map(Line::from)
+            .collect();
+        Paragraph::new(lines).centered().render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letters_keep_their_best_state() {
+        let revelations = [
+            WordRevelation::compute("speed", "abide"),
+            WordRevelation::compute("abide", "abide"),
+            WordRevelation::compute("dread", "abide"),
+        ];
+        let keyboard = Keyboard::from_revelations(&revelations);
+        // `d` was misplaced in speed, then correct in abide, then misplaced again
+        assert_eq!(keyboard.state('d'), Some(&State::Correct));
+        assert_eq!(keyboard.state('e'), Some(&State::Correct));
+        assert_eq!(keyboard.state('s'), Some(&State::Wrong));
+        assert_eq!(keyboard.state('r'), Some(&State::Wrong));
+        assert_eq!(keyboard.state('z'), None);
+        assert!(rank(&State::Correct) > rank(&State::Change));
+        assert!(rank(&State::Change) > rank(&State::Wrong));
+    }
+
+    #[test]
+    fn extra_letters_get_their_own_rows() {
+        let alphabet: Vec<char> = "abcdeéèêëàâîïôûùç".chars().collect();
+        let keyboard = Keyboard::default().with_alphabet(&alphabet);
+        assert_eq!(keyboard.extra.len(), 12);
+        assert_eq!(keyboard.height(), 5);
+        assert_eq!(Keyboard::default().height(), 3);
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::Line,
+    widgets::{Paragraph, Widget},
+};
+
+/// Messages shown at once. Past it the oldest messages that expire are
+/// dropped, sticky ones stay and make the area taller.
+pub const VISIBLE: usize = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Severity {
+    Info,
+    Success,
+    Hint,
+    Error,
+}
+
+impl Severity {
+    fn lifetime(self) -> Duration {
+        match self {
+            Severity::Info | Severity::Success => Duration::from_secs(4),
+            Severity::Hint => Duration::from_secs(8),
+            Severity::Error => Duration::from_secs(3),
+        }
+    }
+
+    fn style(self) -> Style {
+        match self {
+            Severity::Info => Style::new().white(),
+            Severity::Success => Style::new().green().bold(),
+            Severity::Hint => Style::new().cyan(),
+            Severity::Error => Style::new().red().bold(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Message {
+    pub text: String,
+    pub severity: Severity,
+    /// `None` keeps the message until the game restarts.
+    pub expires_at: Option<Instant>,
+}
+
+/// Status messages under the board, newest last.
+#[derive(Default)]
+pub struct Messages {
+    queue: VecDeque<Message>,
+}
+
+impl Messages {
+    /// Shows `text` for as long as its severity warrants.
+    pub fn push(&mut self, severity: Severity, text: impl Into<String>) {
+        let expires_at = Some(Instant::now() + severity.lifetime());
+        self.insert(Message {
+            text: text.into(),
+            severity,
+            expires_at,
+        });
+    }
+
+    /// Shows `text` until the game restarts.
+    pub fn push_sticky(&mut self, severity: Severity, text: impl Into<String>) {
+        self.insert(Message {
+            text: text.into(),
+            severity,
+            expires_at: None,
+        });
+    }
+
+    fn insert(&mut self, message: Message) {
+        // repeating the newest message only refreshes it
+        self.queue.retain(|m| m.text != message.text);
+        self.queue.push_back(message);
+        while self.queue.len() > VISIBLE {
+            let newest = self.queue.len() - 1;
+            match self
+                .queue
+                .iter()
+                .take(newest)
+                .position(|m| m.expires_at.is_some())
+            {
+                Some(oldest) => self.queue.remove(oldest),
+                None => break,
+            };
+        }
+    }
+
+    /// Lines needed to show every message, never fewer than `VISIBLE`.
+    pub fn height(&self) -> u16 {
+        self.queue.len().max(VISIBLE) as u16
+    }
+
+    pub fn expire(&mut self, now: Instant) {
+        self.queue
+            .retain(|m| m.expires_at.is_none_or(|expires_at| expires_at > now));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Message> {
+        self.queue.iter()
+    }
+}
+
+impl Widget for &Messages {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lines: Vec<Line> = self
+            .iter()
+            .map(|m| Line::styled(m.text.as_str(), m.severity.style()))
+            .collect();
+        Paragraph::new(lines).centered().render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(messages: &Messages) -> Vec<&str> {
+        messages.iter().map(|m| m.text.as_str()).collect()
+    }
+
+    #[test]
+    fn repeats_refresh_and_old_messages_drop() {
+        let mut messages = Messages::default();
+        messages.push(Severity::Error, "Word must be of 5 letters");
+        messages.push(Severity::Hint, "Try: crane");
+        messages.push(Severity::Error, "Word must be of 5 letters");
+        assert_eq!(
+            texts(&messages),
+            ["Try: crane", "Word must be of 5 letters"]
+        );
+        messages.push(Severity::Info, "New game");
+        assert_eq!(messages.iter().count(), VISIBLE);
+        assert_eq!(texts(&messages), ["Word must be of 5 letters", "New game"]);
+    }
+
+    #[test]
+    fn messages_expire_by_severity_unless_sticky() {
+        let mut messages = Messages::default();
+        messages.push_sticky(Severity::Error, "Can't read the word list");
+        messages.push(Severity::Error, "Word doesn't exist in dictionary");
+        let now = Instant::now();
+        messages.expire(now + Severity::Error.lifetime() / 2);
+        assert_eq!(messages.iter().count(), 2);
+        messages.expire(now + Severity::Error.lifetime());
+        assert_eq!(texts(&messages), ["Can't read the word list"]);
+
+        messages.push(Severity::Hint, "Try: crane");
+        messages.expire(Instant::now() + Severity::Error.lifetime());
+        assert_eq!(messages.iter().count(), 2);
+        messages.expire(Instant::now() + Severity::Hint.lifetime());
+        assert_eq!(texts(&messages), ["Can't read the word list"]);
+    }
+
+    #[test]
+    fn sticky_messages_are_never_dropped() {
+        let mut messages = Messages::default();
+        messages.push_sticky(Severity::Error, "Can't read the word list");
+        messages.push(Severity::Error, "Word must be of 5 letters");
+        messages.push(Severity::Error, "Word doesn't exist in dictionary");
+        assert_eq!(
+            texts(&messages),
+            [
+                "Can't read the word list",
+                "Word doesn't exist in dictionary"
+            ]
+        );
+        messages.push_sticky(Severity::Success, "Congratulations");
+        messages.push_sticky(Severity::Info, "Press c to copy your result");
+        messages.push_sticky(Severity::Error, "Stats not saved");
+        assert_eq!(
+            texts(&messages),
+            [
+                "Can't read the word list",
+                "Congratulations",
+                "Press c to copy your result",
+                "Stats not saved"
+            ]
+        );
+        assert_eq!(messages.height(), 4);
+        messages.push(Severity::Hint, "Try: crane");
+        assert_eq!(messages.iter().last().unwrap().text, "Try: crane");
+        assert_eq!(messages.height(), 5);
+    }
+}
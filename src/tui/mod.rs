@@ -0,0 +1,15 @@
+pub mod animation;
+pub mod bench;
+pub mod board;
+pub mod cli;
+pub mod commands;
+pub mod daily_log;
+pub mod game;
+pub mod host;
+pub mod keyboard;
+pub mod message;
+pub mod paths;
+pub mod save;
+pub mod share;
+pub mod stats;
+pub mod text;
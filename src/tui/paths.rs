@@ -0,0 +1,51 @@
+//! Per-user directories the game keeps its files in.
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+const APP_DIR: &str = "wordle";
+
+/// `$XDG_CACHE_HOME/wordle`, falling back to `~/.cache/wordle`.
+pub fn cache_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_CACHE_HOME", ".cache")
+}
+
+/// `$XDG_DATA_HOME/wordle`, falling back to `~/.local/share/wordle`.
+pub fn data_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_DATA_HOME", ".local/share")
+}
+
+/// Contents of the file at `path`, `None` when it doesn't exist yet.
+pub fn read(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Writes `contents` to `path`, creating its directory if needed.
+pub fn write(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+/// Error for a `line` of a `file` that can't be understood.
+pub fn bad_line(file: &str, line: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("bad {file} line `{line}`"),
+    )
+}
+
+fn xdg_dir(variable: &str, fallback: &str) -> Option<PathBuf> {
+    let base = match env::var_os(variable) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(fallback),
+    };
+    Some(base.join(APP_DIR))
+}
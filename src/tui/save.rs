@@ -0,0 +1,184 @@
+//! An unfinished game kept between sessions.
+
+use crate::tui::paths;
+use wordle::{Puzzle, Settings, daily};
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use time::Date;
+/// XORed over the answer so a glance at the file doesn't spoil it.
+const KEY: &[u8] = b"no peeking";
+
+/// Unfinished game kept on exit, as `key value` lines in the data directory.
+/// The revelations are recomputed from the answer and the guesses.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SavedGame {
+    /// Rules the game was played with.
+    pub settings: Settings,
+    /// Date of the puzzle in daily mode.
+    pub daily: Option<Date>,
+    /// The word to find.
+    pub answer: String,
+    /// Guesses accepted so far, oldest first.
+    pub guesses: Vec<String>,
+    /// Time played before the game was saved.
+    pub elapsed: Duration,
+}
+
+impl SavedGame {
+    /// Name of the file in the data directory.
+    pub const FILE_NAME: &str = "saved-game.txt";
+
+    /// Reads the game saved at `path`, `None` when there is none.
+    pub fn load(path: &Path) -> io::Result<Option<SavedGame>> {
+        paths::read(path)?
+            .map(|contents| SavedGame::parse(&contents))
+            .transpose()
+    }
+
+    /// Writes the game to `path`, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        paths::write(path, &self.to_string())
+    }
+
+    /// Removes the game saved at `path`, if any.
+    pub fn discard(path: &Path) -> io::Result<()> {
+        match fs::remove_file(path) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+            _ => Ok(()),
+        }
+    }
+
+    fn parse(contents: &str) -> io::Result<SavedGame> {
+        let invalid = |line: &str| paths::bad_line("saved game", line);
+        let mut settings = Settings::default();
+        let mut daily = None;
+        let mut answer = None;
+        let mut guesses = vec![];
+        let mut elapsed = Duration::ZERO;
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            let (key, value) = line.split_once(' ').ok_or_else(|| invalid(line))?;
+            match (key, value.split_once(' ')) {
+                ("answer", None) => answer = Some(reveal(value).ok_or_else(|| invalid(line))?),
+                ("guess", None) => guesses.push(value.to_string()),
+                ("length", None) => {
+                    settings.word_length = value.parse().map_err(|_| invalid(line))?
+                }
+                ("hard_mode", None) => {
+                    settings.hard_mode = value.parse().map_err(|_| invalid(line))?
+                }
+                ("attempts", None) if value == "zen" => settings.max_attempts = None,
+                ("attempts", None) => {
+                    settings.max_attempts = Some(value.parse().map_err(|_| invalid(line))?)
+                }
+                ("words", _) => settings.words_path = Some(PathBuf::from(value)),
+                ("answers", _) => settings.answers_path = Some(PathBuf::from(value)),
+                ("elapsed", None) => {
+                    elapsed = Duration::from_secs(value.parse().map_err(|_| invalid(line))?)
+                }
+                ("puzzle", None) if value == "random" => settings.puzzle = Puzzle::Random,
+                ("puzzle", Some(("seed", seed))) => {
+                    settings.puzzle = Puzzle::Seed(seed.parse().map_err(|_| invalid(line))?)
+                }
+                ("puzzle", Some(("code", code))) => {
+                    settings.puzzle = Puzzle::Code(code.parse().map_err(|_| invalid(line))?)
+                }
+                ("puzzle", Some(("daily", number))) => {
+                    settings.puzzle = Puzzle::Daily;
+                    daily = Some(daily::date_of(number.parse().map_err(|_| invalid(line))?));
+                }
+                _ => return Err(invalid(line)),
+            }
+        }
+        Ok(SavedGame {
+            settings,
+            daily,
+            answer: answer.ok_or_else(|| invalid("answer"))?,
+            guesses,
+            elapsed,
+        })
+    }
+}
+
+impl fmt::Display for SavedGame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let settings = &self.settings;
+        writeln!(f, "answer {}", obfuscate(&self.answer))?;
+        writeln!(f, "length {}", settings.word_length)?;
+        writeln!(f, "hard_mode {}", settings.hard_mode)?;
+        match settings.max_attempts {
+            Some(max) => writeln!(f, "attempts {max}")?,
+            None => writeln!(f, "attempts zen")?,
+        }
+        if let Some(path) = &settings.words_path {
+            writeln!(f, "words {}", path.display())?;
+        }
+        if let Some(path) = &settings.answers_path {
+            writeln!(f, "answers {}", path.display())?;
+        }
+        match (settings.puzzle, self.daily) {
+            (Puzzle::Daily, Some(date)) => {
+                writeln!(f, "puzzle daily {}", daily::puzzle_number(date))?
+            }
+            (Puzzle::Seed(seed), _) => writeln!(f, "puzzle seed {seed}")?,
+            (Puzzle::Code(code), _) => writeln!(f, "puzzle code {code}")?,
+            _ => writeln!(f, "puzzle random")?,
+        }
+        writeln!(f, "elapsed {}", self.elapsed.as_secs())?;
+        for guess in &self.guesses {
+            writeln!(f, "guess {guess}")?;
+        }
+        Ok(())
+    }
+}
+
+fn obfuscate(answer: &str) -> String {
+    answer
+        .bytes()
+        .zip(KEY.iter().cycle())
+        .map(|(byte, key)| format!("{:02x}", byte ^ key))
+        .collect()
+}
+
+fn reveal(hex: &str) -> Option<String> {
+    let bytes = (0..hex.len())
+        .step_by(2)
+        .zip(KEY.iter().cycle())
+        .map(|(i, key)| {
+            let byte = u8::from_str_radix(hex.get(i..i + 2)?, 16).ok()?;
+            Some(byte ^ key)
+        })
+        .collect::<Option<Vec<u8>>>()?;
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_game_round_trips_without_showing_the_answer() {
+        let saved = SavedGame {
+            settings: Settings {
+                hard_mode: true,
+                max_attempts: None,
+                words_path: Some(PathBuf::from("/team/word list.txt")),
+                puzzle: Puzzle::Daily,
+                ..Settings::default()
+            },
+            daily: Some(daily::date_of(1582)),
+            answer: String::from("élève"),
+            guesses: vec![String::from("crane"), String::from("lèvre")],
+            elapsed: Duration::from_secs(83),
+        };
+        let text = saved.to_string();
+        assert!(!text.contains("élève"));
+        assert_eq!(SavedGame::parse(&text).unwrap(), saved);
+        assert!(SavedGame::parse("guess crane\n").is_err());
+        assert!(SavedGame::parse("answer zz\n").is_err());
+    }
+}
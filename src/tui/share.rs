@@ -0,0 +1,99 @@
+//! Result grids to paste in chat.
+
+use wordle::{State, WordRevelation};
+
+use std::io::{self, Write};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The result as posted in chat: a `Wordle N 4/6` header, `X` for a lost
+/// game and `*` in hard mode, then one row of squares per guess.
+pub fn summary(
+    name: &str,
+    revelations: &[WordRevelation],
+    won: bool,
+    max_attempts: Option<usize>,
+    hard_mode: bool,
+) -> String {
+    let score = if won {
+        revelations.len().to_string()
+    } else {
+        String::from("X")
+    };
+    let mut text = match max_attempts {
+        Some(max) => format!("Wordle {name} {score}/{max}"),
+        None => format!("Wordle {name} {score}"),
+    };
+    if hard_mode {
+        text.push('*');
+    }
+    text.push('\n');
+    for revelation in revelations {
+        text.push('\n');
+        text.extend(revelation.into_iter().map(|r| match r.state {
+            State::Correct => '🟩',
+            State::Change => '🟨',
+            State::Wrong => '⬛',
+        }));
+    }
+    text
+}
+
+/// Puts `text` in the clipboard through the terminal with the OSC 52 escape,
+/// which also works over SSH. Terminals that don't support it ignore it.
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", base64(text.as_bytes()))?;
+    stdout.flush()
+}
+
+fn base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | (*byte as u32) << (16 - 8 * i)
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64[(group >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_lists_a_row_per_guess() {
+        let revelations = [
+            WordRevelation::compute("crane", "caper"),
+            WordRevelation::compute("caper", "caper"),
+        ];
+        assert_eq!(
+            summary("1582", &revelations, true, Some(6), false),
+            "Wordle 1582 2/6\n\n🟩🟨🟨⬛🟨\n🟩🟩🟩🟩🟩"
+        );
+        assert!(
+            summary("1582", &revelations[..1], false, None, true).starts_with("Wordle 1582 X*\n")
+        );
+    }
+
+    #[test]
+    fn base64_pads() {
+        let encoded: Vec<String> = ["", "f", "fo", "foo", "foob", "fooba", "foobar"]
+            .iter()
+            .map(|text| base64(text.as_bytes()))
+            .collect();
+        assert_eq!(
+            encoded,
+            [
+                "", "Zg==", "Zm8=", "Zm9v", "Zm9vYg==", "Zm9vYmE=", "Zm9vYmFy"
+            ]
+        );
+    }
+}
@@ -0,0 +1,118 @@
+//! Win rate, streaks and guess distribution across games.
+
+use crate::tui::paths;
+
+use std::{collections::BTreeMap, io, path::Path};
+
+/// Results of every finished game, kept as `key value` lines in the data
+/// directory.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stats {
+    /// Games finished.
+    pub played: usize,
+    /// Games won.
+    pub won: usize,
+    /// Games won in a row up to the last one.
+    pub current_streak: usize,
+    /// Longest run of games won in a row.
+    pub max_streak: usize,
+    /// Won games by the number of guesses they took.
+    pub distribution: BTreeMap<usize, usize>,
+}
+
+impl Stats {
+    /// Name of the file in the data directory.
+    pub const FILE_NAME: &str = "stats.txt";
+
+    /// Reads the stats at `path`, starting afresh when there are none yet.
+    pub fn load(path: &Path) -> io::Result<Stats> {
+        match paths::read(path)? {
+            Some(contents) => Stats::parse(&contents),
+            None => Ok(Stats::default()),
+        }
+    }
+
+    /// Writes the stats to `path`, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        paths::write(path, &self.to_string())
+    }
+
+    fn parse(contents: &str) -> io::Result<Stats> {
+        let invalid = |line: &str| paths::bad_line("stats", line);
+        let mut stats = Stats::default();
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            let fields: Vec<usize> = line
+                .split_whitespace()
+                .skip(1)
+                .map(|field| field.parse().map_err(|_| invalid(line)))
+                .collect::<io::Result<_>>()?;
+            match (line.split_whitespace().next(), fields.as_slice()) {
+                (Some("played"), [n]) => stats.played = *n,
+                (Some("won"), [n]) => stats.won = *n,
+                (Some("current_streak"), [n]) => stats.current_streak = *n,
+                (Some("max_streak"), [n]) => stats.max_streak = *n,
+                (Some("guesses"), [guesses, n]) => {
+                    stats.distribution.insert(*guesses, *n);
+                }
+                _ => return Err(invalid(line)),
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Counts a finished game that took `guesses` guesses.
+    pub fn record(&mut self, won: bool, guesses: usize) {
+        self.played += 1;
+        if won {
+            self.won += 1;
+            self.current_streak += 1;
+            self.max_streak = self.max_streak.max(self.current_streak);
+            *self.distribution.entry(guesses).or_insert(0) += 1;
+        } else {
+            self.current_streak = 0;
+        }
+    }
+
+    /// Percentage of games won.
+    pub fn win_rate(&self) -> f64 {
+        if self.played == 0 {
+            0.0
+        } else {
+            self.won as f64 * 100.0 / self.played as f64
+        }
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "played {}", self.played)?;
+        writeln!(f, "won {}", self.won)?;
+        writeln!(f, "current_streak {}", self.current_streak)?;
+        writeln!(f, "max_streak {}", self.max_streak)?;
+        for (guesses, n) in &self.distribution {
+            writeln!(f, "guesses {guesses} {n}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_tracks_streaks_and_round_trips() {
+        let mut stats = Stats::default();
+        stats.record(true, 3);
+        stats.record(true, 4);
+        stats.record(false, 6);
+        stats.record(true, 3);
+        assert_eq!(stats.played, 4);
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(stats.max_streak, 2);
+        assert_eq!(stats.distribution.get(&3), Some(&2));
+        assert_eq!(stats.win_rate(), 75.0);
+        assert_eq!(Stats::parse(&stats.to_string()).unwrap(), stats);
+        assert!(Stats::parse("played many\n").is_err());
+    }
+}
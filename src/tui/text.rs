@@ -0,0 +1,96 @@
+use std::io::{self, BufRead, Write};
+
+use wordle::{Config, Engine, Outcome, Settings, daily};
+
+/// Engine for a game of `settings`, failing on word lists that can't be read
+/// rather than falling back to the built-in ones.
+pub fn engine(settings: Settings) -> Result<Engine, String> {
+    let mut config = Config::load(&settings).map_err(|error| error.to_string())?;
+    if let Some(index) = settings
+        .puzzle
+        .answer_index(config.answers(), daily::today())?
+    {
+        config.choose_answer(index);
+    }
+    Ok(Engine::new(config, settings))
+}
+
+/// Plays a game with one guess per line of `input`, writing the feedback of
+/// each as `crane -> B Y B G B` so it reads well in a screen reader or a pipe.
+pub fn play(engine: &mut Engine, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let word_length = engine.config().word_length();
+    let max_attempts = engine.settings().max_attempts;
+    match max_attempts {
+        Some(max) => writeln!(
+            output,
+            "Guess the {word_length} letter word in {max} attempts"
+        )?,
+        None => writeln!(output, "Guess the {word_length} letter word")?,
+    }
+    output.flush()?;
+    for line in input.lines() {
+        let line = line?;
+        let guess = line.trim();
+        if guess.is_empty() {
+            continue;
+        }
+        let outcome = engine.play(guess);
+        match &outcome {
+            Outcome::Accepted | Outcome::Won { .. } | Outcome::Lost { .. } => {
+                let revelation = engine.revelations().last().expect("guess was played");
+                writeln!(output, "{} -> {revelation}", guess.to_lowercase())?;
+            }
+            _ => {
+                if let Some(message) = outcome.message() {
+                    writeln!(output, "{guess}: {message}")?;
+                }
+            }
+        }
+        if let Some(summary) = engine.summary() {
+            writeln!(output, "{summary}")?;
+            return output.flush();
+        }
+        output.flush()?;
+    }
+    writeln!(
+        output,
+        "The true word was: {}",
+        engine.config().chosen_word()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wordle::Puzzle;
+
+    #[test]
+    fn seeded_game_matches_golden_output() {
+        let mut engine = engine(Settings {
+            puzzle: Puzzle::Seed(7),
+            ..Settings::default()
+        })
+        .unwrap();
+        let mut output = vec![];
+        play(
+            &mut engine,
+            "crane\n\nxyzzy\ncranes\nHAZE1\nslate\nhazel\nextra\n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\
+Guess the 5 letter word in 6 attempts
+crane -> B B Y B Y
+xyzzy: Word doesn't exist in dictionary
+cranes: Word must be of 5 letters
+HAZE1: '1' is not used by this word list
+slate -> B Y Y B Y
+hazel -> G G G G G
+Solved in 3/6
+"
+        );
+    }
+}